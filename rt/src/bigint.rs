@@ -0,0 +1,139 @@
+//! ReScript's `bigint`, written as a decimal string. JSON numbers are read
+//! into JavaScript floats and lose precision above 2^53, so 64- and 128-bit
+//! integers are passed as strings instead.
+//!
+//! ```ignore
+//! #[derive(SerializeDto, DeserializeDto)]
+//! struct Account {
+//!     #[serde(with = "serde_derive_rescript_rt::bigint")]
+//!     balance: i128,
+//! }
+//! ```
+//!
+//! Such fields are exported as `bigint` by `RescriptType`, as is a [`BigInt`]
+//! referred to as `serde_derive_rescript_rt::BigInt`.
+
+use serde::de::{Deserialize, Deserializer, Error, Unexpected, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// An integer written as `bigint`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BigInt<T>(pub T);
+
+impl<T> From<T> for BigInt<T> {
+    fn from(value: T) -> Self {
+        BigInt(value)
+    }
+}
+
+impl<T> Serialize for BigInt<T>
+where
+    T: Display,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for BigInt<T>
+where
+    T: FromStr,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer).map(BigInt)
+    }
+}
+
+/// Writes the integer as a decimal string.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Display,
+    S: Serializer,
+{
+    serializer.collect_str(value)
+}
+
+/// Reads a decimal string. Human-readable formats may hold an integer as
+/// well, as long as it fits the target type.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromStr,
+    D: Deserializer<'de>,
+{
+    if deserializer.is_human_readable() {
+        deserializer.deserialize_any(BigIntVisitor(PhantomData))
+    } else {
+        deserializer.deserialize_str(BigIntVisitor(PhantomData))
+    }
+}
+
+struct BigIntVisitor<T>(PhantomData<T>);
+
+impl<T> BigIntVisitor<T>
+where
+    T: FromStr,
+{
+    fn parse<E>(value: &str, unexpected: Unexpected) -> Result<T, E>
+    where
+        E: Error,
+    {
+        value
+            .parse()
+            .map_err(|_| E::invalid_value(unexpected, &"a bigint"))
+    }
+}
+
+impl<'de, T> Visitor<'de> for BigIntVisitor<T>
+where
+    T: FromStr,
+{
+    type Value = T;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a bigint")
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<T, E>
+    where
+        E: Error,
+    {
+        Self::parse(value, Unexpected::Str(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<T, E>
+    where
+        E: Error,
+    {
+        Self::parse(&value.to_string(), Unexpected::Signed(value))
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<T, E>
+    where
+        E: Error,
+    {
+        Self::parse(&value.to_string(), Unexpected::Unsigned(value))
+    }
+
+    fn visit_i128<E>(self, value: i128) -> Result<T, E>
+    where
+        E: Error,
+    {
+        Self::parse(&value.to_string(), Unexpected::Other("integer"))
+    }
+
+    fn visit_u128<E>(self, value: u128) -> Result<T, E>
+    where
+        E: Error,
+    {
+        Self::parse(&value.to_string(), Unexpected::Other("integer"))
+    }
+}
@@ -0,0 +1,89 @@
+//! ReScript's `Date.t` (`Js.Date.t`), written as milliseconds since the Unix
+//! epoch, i.e. what `Date.getTime` returns.
+//!
+//! ```ignore
+//! #[derive(SerializeDto, DeserializeDto)]
+//! struct Post {
+//!     #[serde(with = "serde_derive_rescript_rt::date")]
+//!     created_at: SystemTime,
+//! }
+//! ```
+//!
+//! Such fields are exported as `Date.t` by `RescriptType`, as is a [`Date`]
+//! referred to as `serde_derive_rescript_rt::Date`.
+
+use serde::de::{Deserialize, Deserializer, Error, Unexpected};
+use serde::ser::{Serialize, Serializer};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A [`SystemTime`] written as `Date.t`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date(pub SystemTime);
+
+impl From<SystemTime> for Date {
+    fn from(time: SystemTime) -> Self {
+        Date(time)
+    }
+}
+
+impl From<Date> for SystemTime {
+    fn from(date: Date) -> Self {
+        date.0
+    }
+}
+
+impl Serialize for Date {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Date {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize(deserializer).map(Date)
+    }
+}
+
+/// Writes the time as epoch milliseconds. Sub-millisecond precision is kept
+/// as the fraction.
+pub fn serialize<S>(time: &SystemTime, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let millis = |since: Duration| {
+        since.as_secs() as f64 * 1000.0 + f64::from(since.subsec_nanos()) / 1_000_000.0
+    };
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => serializer.serialize_f64(millis(since)),
+        Err(err) => serializer.serialize_f64(-millis(err.duration())),
+    }
+}
+
+/// Reads epoch milliseconds. `NaN`, i.e. an `Invalid Date`, and times out of
+/// the range of [`SystemTime`] are rejected.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<SystemTime, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let millis = f64::deserialize(deserializer)?;
+    let invalid = || D::Error::invalid_value(Unexpected::Float(millis), &"a valid Date.t");
+    let abs = millis.abs();
+    if abs.is_nan() || abs >= u64::MAX as f64 {
+        return Err(invalid());
+    }
+    // Whole milliseconds are read exactly, the fraction to the nanosecond
+    let nanos = ((abs - abs.trunc()) * 1_000_000.0).round() as u64;
+    let since = Duration::from_millis(abs.trunc() as u64) + Duration::from_nanos(nanos);
+    let time = if millis < 0.0 {
+        UNIX_EPOCH.checked_sub(since)
+    } else {
+        UNIX_EPOCH.checked_add(since)
+    };
+    time.ok_or_else(invalid)
+}
@@ -0,0 +1,274 @@
+//! ReScript's `Dict.t`, i.e. a JavaScript object used as a map.
+//!
+//! JavaScript enumerates the keys of an object that look like array indices
+//! (`"0"`, `"1"`, ...) first, in ascending order, and the other keys in the
+//! order they were added. [`Dict`] keeps its entries in that order, so a dict
+//! goes through ReScript unchanged. The `with` functions write a `HashMap` or
+//! a `BTreeMap` in a stable order: index keys first, then the other keys
+//! sorted.
+//!
+//! ```ignore
+//! #[derive(SerializeDto, DeserializeDto)]
+//! struct Translations {
+//!     #[serde(with = "serde_derive_rescript_rt::dict")]
+//!     messages: HashMap<String, String>,
+//! }
+//! ```
+
+use serde::de::{Deserialize, Deserializer, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use std::collections::{btree_map, BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::iter::Chain;
+use std::marker::PhantomData;
+use std::vec;
+
+/// A map from strings to `V` that enumerates its entries like a JavaScript
+/// object.
+#[derive(Clone)]
+pub struct Dict<V> {
+    /// Entries whose key is an array index, by index.
+    indices: BTreeMap<u32, (String, V)>,
+    /// The other entries, in the order they were added.
+    entries: Vec<(String, V)>,
+    /// Position of every key of `entries`.
+    positions: HashMap<String, usize>,
+}
+
+impl<V> Dict<V> {
+    pub fn new() -> Self {
+        Dict {
+            indices: BTreeMap::new(),
+            entries: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len() + self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        match array_index(key) {
+            Some(index) => self.indices.get(&index).map(|(_, v)| v),
+            None => self.positions.get(key).map(|&i| &self.entries[i].1),
+        }
+    }
+
+    pub fn get_mut(&mut self, key: &str) -> Option<&mut V> {
+        match array_index(key) {
+            Some(index) => self.indices.get_mut(&index).map(|(_, v)| v),
+            None => match self.positions.get(key) {
+                Some(&i) => Some(&mut self.entries[i].1),
+                None => None,
+            },
+        }
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Sets the value of a key. An existing key keeps its place and its
+    /// previous value is returned.
+    pub fn insert(&mut self, key: String, value: V) -> Option<V> {
+        if let Some(index) = array_index(&key) {
+            return self.indices.insert(index, (key, value)).map(|(_, v)| v);
+        }
+        if let Some(&i) = self.positions.get(&key) {
+            return Some(std::mem::replace(&mut self.entries[i].1, value));
+        }
+        self.positions.insert(key.clone(), self.entries.len());
+        self.entries.push((key, value));
+        None
+    }
+
+    /// Removes a key. The entries added after a key that isn't an array index
+    /// move up, which takes linear time.
+    pub fn remove(&mut self, key: &str) -> Option<V> {
+        if let Some(index) = array_index(key) {
+            return self.indices.remove(&index).map(|(_, v)| v);
+        }
+        let i = self.positions.remove(key)?;
+        for position in self.positions.values_mut() {
+            if *position > i {
+                *position -= 1;
+            }
+        }
+        Some(self.entries.remove(i).1)
+    }
+
+    /// Entries in the order JavaScript enumerates them.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &V)> {
+        self.indices
+            .values()
+            .chain(&self.entries)
+            .map(|(k, v)| (k.as_str(), v))
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+// Dicts are equal if they enumerate the same entries in the same order
+impl<V: PartialEq> PartialEq for Dict<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<V: Eq> Eq for Dict<V> {}
+
+impl<V: Hash> Hash for Dict<V> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for entry in self.iter() {
+            entry.hash(state);
+        }
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for Dict<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> Default for Dict<V> {
+    fn default() -> Self {
+        Dict::new()
+    }
+}
+
+impl<V> FromIterator<(String, V)> for Dict<V> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, V)>,
+    {
+        let mut dict = Dict::new();
+        dict.extend(iter);
+        dict
+    }
+}
+
+impl<V> Extend<(String, V)> for Dict<V> {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (String, V)>,
+    {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<V> IntoIterator for Dict<V> {
+    type Item = (String, V);
+    type IntoIter = Chain<btree_map::IntoValues<u32, (String, V)>, vec::IntoIter<(String, V)>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.indices.into_values().chain(self.entries)
+    }
+}
+
+impl<V> Serialize for Dict<V>
+where
+    V: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(self.iter())
+    }
+}
+
+impl<'de, V> Deserialize<'de> for Dict<V>
+where
+    V: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(DictVisitor(PhantomData))
+    }
+}
+
+struct DictVisitor<V>(PhantomData<V>);
+
+impl<'de, V> Visitor<'de> for DictVisitor<V>
+where
+    V: Deserialize<'de>,
+{
+    type Value = Dict<V>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a dict")
+    }
+
+    // A repeated key keeps its first place and its last value, like
+    // `JSON.parse`
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut dict = Dict::new();
+        while let Some((key, value)) = map.next_entry()? {
+            dict.insert(key, value);
+        }
+        Ok(dict)
+    }
+}
+
+/// Writes the map with index keys first, in ascending order, then the other
+/// keys sorted.
+pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: AsRef<str> + 'a,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    let mut entries: Vec<_> = map.into_iter().map(|(k, v)| (k.as_ref(), v)).collect();
+    entries.sort_by(|(a, _), (b, _)| match (array_index(a), array_index(b)) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => a.cmp(b),
+    });
+    let mut state = serializer.serialize_map(Some(entries.len()))?;
+    for (key, value) in entries {
+        state.serialize_entry(key, value)?;
+    }
+    state.end()
+}
+
+/// Reads the map as usual. Provided so the module can be used with
+/// `#[serde(with = "...")]`.
+pub fn deserialize<'de, M, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    M::deserialize(deserializer)
+}
+
+/// The index a key stands for if JavaScript treats it as an array index, i.e.
+/// a canonical decimal number below 2^32 - 1.
+fn array_index(key: &str) -> Option<u32> {
+    if key.len() > 1 && key.starts_with('0') || !key.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    key.parse().ok().filter(|&index| index != u32::MAX)
+}
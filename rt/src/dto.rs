@@ -0,0 +1,101 @@
+//! The ReScript form of types that also derive upstream serde's `Serialize`
+//! and `Deserialize`.
+//!
+//! With `#[rescript(dto)]`, `#[derive(SerializeDto, DeserializeDto)]`
+//! implements [`SerializeDto`] and [`DeserializeDto`] instead of the serde
+//! traits. [`Dto`] turns them back into `Serialize` and `Deserialize`:
+//!
+//! ```ignore
+//! #[derive(Serialize, Deserialize, SerializeDto, DeserializeDto)]
+//! #[rescript(dto)]
+//! struct Event {
+//!     kind: Kind,
+//!     #[rescript(dto)]
+//!     payload: Payload,
+//! }
+//!
+//! serde_json::to_string(&event)?; // upstream serde
+//! serde_json::to_string(&Dto(&event))?; // ReScript
+//! let Dto(event) = serde_json::from_str::<Dto<Event>>(json)?;
+//! ```
+//!
+//! Fields are still written by their `Serialize` impl. A field holding another
+//! `#[rescript(dto)]` type is marked `#[rescript(dto)]` as well to be written
+//! in the ReScript form, which uses the functions of this module. Such types
+//! nested in a collection are wrapped in [`Dto`] instead.
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+/// `Serialize` in the ReScript form, implemented by `#[rescript(dto)]` types.
+pub trait SerializeDto {
+    fn serialize_dto<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer;
+}
+
+/// `Deserialize` in the ReScript form, implemented by `#[rescript(dto)]`
+/// types.
+pub trait DeserializeDto<'de>: Sized {
+    fn deserialize_dto<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>;
+}
+
+impl<T> SerializeDto for &T
+where
+    T: SerializeDto + ?Sized,
+{
+    fn serialize_dto<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (**self).serialize_dto(serializer)
+    }
+}
+
+/// A value written and read in the ReScript form.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dto<T>(pub T);
+
+impl<T> Serialize for Dto<T>
+where
+    T: SerializeDto,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_dto(serializer)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Dto<T>
+where
+    T: DeserializeDto<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize_dto(deserializer).map(Dto)
+    }
+}
+
+/// Writes the value in the ReScript form, for `#[rescript(dto)]` fields.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: SerializeDto + ?Sized,
+    S: Serializer,
+{
+    value.serialize_dto(serializer)
+}
+
+/// Reads the value in the ReScript form, for `#[rescript(dto)]` fields.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeDto<'de>,
+    D: Deserializer<'de>,
+{
+    T::deserialize_dto(deserializer)
+}
@@ -0,0 +1,178 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::{fs, io};
+
+/// A type with a generated ReScript module.
+///
+/// Implemented by `#[derive(RescriptType)]`. Types whose ReScript module is
+/// written by hand can implement it with their module name and an empty
+/// `collect`, so they can be referred to from derived types.
+pub trait RescriptType {
+    /// Name of the ReScript module, which types referring to this one use.
+    const NAME: &'static str;
+
+    /// Adds the ReScript module of the type to the collector, after the
+    /// modules of the types it refers to.
+    fn collect(collector: &mut Collector);
+}
+
+/// ReScript module generated for a Rust type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Module {
+    /// Module name, i.e. [`RescriptType::NAME`].
+    pub name: &'static str,
+    /// `module_path!()` of the Rust type.
+    pub path: &'static str,
+    /// `module Name = { ... }` source.
+    pub source: String,
+}
+
+impl Module {
+    fn key(&self) -> (&'static str, &'static str) {
+        (self.path, self.name)
+    }
+}
+
+/// `.res` file holding the modules of one Rust module.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct File {
+    /// File name, i.e. `MyCrate_Api.res` for `my_crate::api`.
+    pub name: String,
+    /// File contents.
+    pub source: String,
+}
+
+/// Gathers ReScript modules of types and their dependencies.
+///
+/// Modules are ordered so that every module comes after the modules it refers
+/// to. Mutually recursive types are not supported by ReScript unless they live
+/// in the same `module rec`, which is not generated.
+///
+/// ```ignore
+/// #[test]
+/// fn export_rescript_types() {
+///     serde_derive_rescript_rt::Collector::new()
+///         .add::<api::Request>()
+///         .add::<api::Response>()
+///         .write("../frontend/src/api")
+///         .unwrap();
+/// }
+/// ```
+#[derive(Default, Debug)]
+pub struct Collector {
+    modules: Vec<Module>,
+    visited: BTreeSet<(&'static str, &'static str)>,
+    dependencies: BTreeMap<(&'static str, &'static str), BTreeSet<&'static str>>,
+    stack: Vec<Module>,
+}
+
+impl Collector {
+    pub fn new() -> Self {
+        Collector::default()
+    }
+
+    /// Adds the module of `T` and of every type it refers to.
+    pub fn add<T: RescriptType + ?Sized>(&mut self) -> &mut Self {
+        T::collect(self);
+        self
+    }
+
+    /// Collected modules, dependencies first.
+    pub fn modules(&self) -> &[Module] {
+        &self.modules
+    }
+
+    /// Collected modules grouped into one file per Rust module, sorted by
+    /// file name. Each file opens the files it depends on.
+    pub fn files(&self) -> Vec<File> {
+        let mut files: BTreeMap<&str, (BTreeSet<String>, Vec<&Module>)> = BTreeMap::new();
+        for module in &self.modules {
+            let (opens, modules) = files.entry(module.path).or_default();
+            if let Some(dependencies) = self.dependencies.get(&module.key()) {
+                opens.extend(
+                    dependencies
+                        .iter()
+                        .filter(|path| **path != module.path)
+                        .map(|path| file_module_name(path)),
+                );
+            }
+            modules.push(module);
+        }
+
+        files
+            .into_iter()
+            .map(|(path, (opens, modules))| {
+                let mut source = "// Generated by serde_derive_rescript. Do not edit.\n".to_owned();
+                if !opens.is_empty() {
+                    source.push('\n');
+                    for open in opens {
+                        source.push_str(&format!("open {}\n", open));
+                    }
+                }
+                for module in modules {
+                    source.push('\n');
+                    source.push_str(&module.source);
+                }
+                File {
+                    name: format!("{}.res", file_module_name(path)),
+                    source,
+                }
+            })
+            .collect()
+    }
+
+    /// Writes [`files`](Collector::files) into `dir`, creating it if needed.
+    pub fn write(&self, dir: impl AsRef<Path>) -> io::Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        for file in self.files() {
+            fs::write(dir.join(file.name), file.source)?;
+        }
+        Ok(())
+    }
+
+    /// Called by generated code before collecting the dependencies of
+    /// `module`. Returns `false` if the module has been collected already.
+    #[doc(hidden)]
+    pub fn enter(&mut self, module: Module) -> bool {
+        if let Some(parent) = self.stack.last() {
+            self.dependencies
+                .entry(parent.key())
+                .or_default()
+                .insert(module.path);
+        }
+        if !self.visited.insert(module.key()) {
+            return false;
+        }
+        self.stack.push(module);
+        true
+    }
+
+    /// Called by generated code once the dependencies of the module passed to
+    /// the matching [`enter`](Collector::enter) are collected.
+    #[doc(hidden)]
+    pub fn leave(&mut self) {
+        if let Some(module) = self.stack.pop() {
+            self.modules.push(module);
+        }
+    }
+}
+
+/// ReScript module name of a file: `my_crate::api` -> `MyCrate_Api`.
+fn file_module_name(path: &str) -> String {
+    path.split("::")
+        .map(|segment| {
+            segment
+                .split('_')
+                .map(|word| {
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                        None => String::new(),
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("_")
+}
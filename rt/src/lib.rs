@@ -0,0 +1,42 @@
+//! Runtime support for `serde_derive_rescript`.
+//!
+//! Code generated by `#[derive(RescriptType)]` implements [`RescriptType`],
+//! which lets a [`Collector`] gather the ReScript modules of a set of types
+//! together with everything they refer to.
+//!
+//! [`Date`], [`BigInt`], [`Dict`] and [`Nullable`] are written the way the
+//! ReScript runtime represents the matching types, and exported as those
+//! types. The [`date`], [`bigint`] and [`dict`] modules also hold functions
+//! for `#[serde(with = "...")]` on fields that keep the standard Rust types,
+//! and [`result`] the ones behind `#[serde(rescript_result)]`.
+//!
+//! Types that need both the upstream serde representation and the ReScript
+//! one use `#[rescript(dto)]` and go through [`Dto`] for the latter.
+//!
+//! Code generated by `#[derive(SerializeDto, DeserializeDto)]` refers to
+//! serde through this crate as well, together with a copy of serde's private
+//! helpers, so the derive isn't tied to one exact serde release.
+
+pub mod bigint;
+pub mod date;
+pub mod dict;
+pub mod dto;
+mod export;
+pub mod nullable;
+#[doc(hidden)]
+pub mod private;
+pub mod result;
+
+pub use crate::bigint::BigInt;
+pub use crate::date::Date;
+pub use crate::dict::Dict;
+pub use crate::dto::{DeserializeDto, Dto, SerializeDto};
+pub use crate::export::{Collector, File, Module, RescriptType};
+pub use crate::nullable::Nullable;
+
+// Used by generated code as `_serde`. Not public API.
+#[doc(hidden)]
+pub mod __serde {
+    pub use crate::private as __private;
+    pub use serde::*;
+}
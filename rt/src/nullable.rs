@@ -0,0 +1,97 @@
+//! ReScript's `Nullable.t`, a value that can be `null` or `undefined`.
+//!
+//! Both are written as `null` and `null` is read as [`Nullable::Null`]. With
+//! `default`, an absent field is read as [`Nullable::Undefined`], and it is
+//! left out again when written with `skip_serializing_if`:
+//!
+//! ```ignore
+//! #[derive(SerializeDto, DeserializeDto)]
+//! struct Patch {
+//!     #[serde(default, skip_serializing_if = "Nullable::is_undefined")]
+//!     name: Nullable<String>,
+//! }
+//! ```
+
+use serde::de::{Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Nullable<T> {
+    Value(T),
+    Null,
+    #[default]
+    Undefined,
+}
+
+impl<T> Nullable<T> {
+    pub fn is_value(&self) -> bool {
+        matches!(self, Nullable::Value(_))
+    }
+
+    pub fn is_null(&self) -> bool {
+        matches!(self, Nullable::Null)
+    }
+
+    pub fn is_undefined(&self) -> bool {
+        matches!(self, Nullable::Undefined)
+    }
+
+    pub fn as_ref(&self) -> Nullable<&T> {
+        match self {
+            Nullable::Value(value) => Nullable::Value(value),
+            Nullable::Null => Nullable::Null,
+            Nullable::Undefined => Nullable::Undefined,
+        }
+    }
+
+    /// The value, if any, like ReScript's `Nullable.toOption`.
+    pub fn into_option(self) -> Option<T> {
+        match self {
+            Nullable::Value(value) => Some(value),
+            Nullable::Null | Nullable::Undefined => None,
+        }
+    }
+}
+
+/// `None` becomes `null`, like ReScript's `Nullable.fromOption`.
+impl<T> From<Option<T>> for Nullable<T> {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(value) => Nullable::Value(value),
+            None => Nullable::Null,
+        }
+    }
+}
+
+impl<T> From<Nullable<T>> for Option<T> {
+    fn from(value: Nullable<T>) -> Self {
+        value.into_option()
+    }
+}
+
+impl<T> Serialize for Nullable<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Nullable::Value(value) => serializer.serialize_some(value),
+            Nullable::Null | Nullable::Undefined => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Nullable<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::deserialize(deserializer).map(Nullable::from)
+    }
+}
@@ -0,0 +1,58 @@
+//! The helpers generated code refers to as `_serde::__private`.
+//!
+//! Adapted from serde's own private module, which is versioned with every
+//! serde release (`__private228`, ...) and can only be used by the
+//! serde_derive of the exact same version. Keeping a copy here lets the
+//! derive work with any serde 1.x. Not public API.
+
+#![allow(clippy::new_without_default)]
+
+mod lib {
+    pub use std::{f32, f64, ptr, slice, str};
+
+    pub use std::clone;
+    pub use std::convert;
+    pub use std::default;
+    pub use std::fmt::{self, Debug, Display, Write as FmtWrite};
+    pub use std::marker::{self, PhantomData};
+    pub use std::option;
+    pub use std::result;
+    pub use std::{cmp, mem};
+
+    pub use std::borrow::{Cow, ToOwned};
+    pub use std::boxed::Box;
+    pub use std::string::{String, ToString};
+    pub use std::vec::Vec;
+}
+
+// Like `?` without the `From::from` error conversion, as in serde.
+macro_rules! tri {
+    ($expr:expr) => {
+        match $expr {
+            Ok(val) => val,
+            Err(err) => return Err(err),
+        }
+    };
+}
+
+pub mod de;
+pub mod ser;
+mod size_hint;
+
+pub use self::lib::clone::Clone;
+pub use self::lib::convert::{From, Into, TryFrom};
+pub use self::lib::default::Default;
+pub use self::lib::fmt::{self, Formatter};
+pub use self::lib::marker::PhantomData;
+pub use self::lib::option::Option::{self, None, Some};
+pub use self::lib::ptr;
+pub use self::lib::result::Result::{self, Err, Ok};
+pub use self::lib::{ToString, Vec};
+pub use std::format_args;
+
+pub use crate::dto::{self, DeserializeDto, SerializeDto};
+pub use crate::result;
+
+pub fn from_utf8_lossy(bytes: &[u8]) -> self::lib::Cow<'_, str> {
+    self::lib::String::from_utf8_lossy(bytes)
+}
@@ -0,0 +1,192 @@
+//! ReScript's `result<'a, 'b>`: `{"TAG": "Ok", "_0": value}` or
+//! `{"TAG": "Error", "_0": error}`.
+//!
+//! `#[serde(rescript_result)]` on a `Result` field uses these functions.
+
+use crate::private::de::{missing_field, Content, ContentDeserializer, ContentVisitor};
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+use std::marker::PhantomData;
+
+pub fn serialize<T, E, S>(value: &Result<T, E>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    E: Serialize,
+    S: Serializer,
+{
+    let mut state = serializer.serialize_struct("result", 2)?;
+    match value {
+        Ok(ok) => {
+            state.serialize_field("TAG", "Ok")?;
+            state.serialize_field("_0", ok)?;
+        }
+        Err(err) => {
+            state.serialize_field("TAG", "Error")?;
+            state.serialize_field("_0", err)?;
+        }
+    }
+    state.end()
+}
+
+/// Reads either tag. The payload is buffered only if it comes before the tag.
+pub fn deserialize<'de, T, E, D>(deserializer: D) -> Result<Result<T, E>, D::Error>
+where
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_struct("result", &["TAG", "_0"], ResultVisitor(PhantomData))
+}
+
+enum Key {
+    Tag,
+    Payload,
+    Ignore,
+}
+
+struct KeyVisitor;
+
+impl<'de> Visitor<'de> for KeyVisitor {
+    type Value = Key;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("field identifier")
+    }
+
+    fn visit_str<Err>(self, value: &str) -> Result<Key, Err>
+    where
+        Err: de::Error,
+    {
+        match value {
+            "TAG" => Ok(Key::Tag),
+            "_0" => Ok(Key::Payload),
+            _ => Ok(Key::Ignore),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Key {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_identifier(KeyVisitor)
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Tag {
+    Ok,
+    Error,
+}
+
+struct TagVisitor;
+
+impl<'de> Visitor<'de> for TagVisitor {
+    type Value = Tag;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("result tag")
+    }
+
+    fn visit_str<Err>(self, value: &str) -> Result<Tag, Err>
+    where
+        Err: de::Error,
+    {
+        match value {
+            "Ok" => Ok(Tag::Ok),
+            "Error" => Ok(Tag::Error),
+            _ => Err(Err::unknown_variant(value, &["Ok", "Error"])),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Tag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(TagVisitor)
+    }
+}
+
+struct ResultVisitor<T, E>(PhantomData<Result<T, E>>);
+
+impl<'de, T, E> Visitor<'de> for ResultVisitor<T, E>
+where
+    T: Deserialize<'de>,
+    E: Deserialize<'de>,
+{
+    type Value = Result<T, E>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("result")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let tag = match seq.next_element::<Tag>()? {
+            Some(tag) => tag,
+            None => return Err(de::Error::invalid_length(0, &self)),
+        };
+        let value = match tag {
+            Tag::Ok => seq.next_element()?.map(Ok),
+            Tag::Error => seq.next_element()?.map(Err),
+        };
+        value.ok_or_else(|| de::Error::invalid_length(1, &self))
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut tag = None;
+        let mut value = None;
+        let mut content: Option<Content<'de>> = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                Key::Tag => {
+                    if tag.is_some() {
+                        return Err(de::Error::duplicate_field("TAG"));
+                    }
+                    tag = Some(map.next_value()?);
+                }
+                Key::Payload => {
+                    if value.is_some() || content.is_some() {
+                        return Err(de::Error::duplicate_field("_0"));
+                    }
+                    match tag {
+                        Some(Tag::Ok) => value = Some(Ok(map.next_value()?)),
+                        Some(Tag::Error) => value = Some(Err(map.next_value()?)),
+                        None => content = Some(map.next_value_seed(ContentVisitor::new())?),
+                    }
+                }
+                Key::Ignore => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        if let Some(value) = value {
+            return Ok(value);
+        }
+        let tag = match tag {
+            Some(tag) => tag,
+            None => return Err(de::Error::missing_field("TAG")),
+        };
+        // A missing payload is read like a missing field, so `Ok(None)`
+        // written by ReScript as `{"TAG": "Ok"}` is accepted
+        match (tag, content) {
+            (Tag::Ok, Some(content)) => {
+                T::deserialize(ContentDeserializer::<A::Error>::new(content)).map(Ok)
+            }
+            (Tag::Error, Some(content)) => {
+                E::deserialize(ContentDeserializer::<A::Error>::new(content)).map(Err)
+            }
+            (Tag::Ok, None) => missing_field("_0").map(Ok),
+            (Tag::Error, None) => missing_field("_0").map(Err),
+        }
+    }
+}
@@ -17,6 +17,8 @@ mod enum_adjacently;
 mod enum_externally;
 mod enum_internally;
 mod enum_mixed;
+mod enum_polymorphic;
+mod enum_unboxed;
 mod enum_untagged;
 mod identifier;
 mod struct_;
@@ -57,6 +59,20 @@ pub fn expand_derive_deserialize(input: &mut syn::DeriveInput) -> syn::Result<To
                 }
             }
         }
+    } else if cont.attrs.dto() {
+        // ReScript: `Deserialize` is left to upstream serde
+        quote! {
+            #[automatically_derived]
+            #allow_deprecated
+            impl #de_impl_generics _serde::#private::DeserializeDto<#delife> for #ident #ty_generics #where_clause {
+                fn deserialize_dto<__D>(__deserializer: __D) -> _serde::#private::Result<Self, __D::Error>
+                where
+                    __D: _serde::Deserializer<#delife>,
+                {
+                    #body
+                }
+            }
+        }
     } else {
         let fn_deserialize_in_place = deserialize_in_place_body(&cont, &params);
 
@@ -233,10 +249,11 @@ fn build_generics(cont: &Container, borrowed: &BorrowedLifetimes) -> syn::Generi
 // attribute, are not deserialized by us so we do not generate a bound. Fields
 // with a `bound` attribute specify their own bound so we do not generate one.
 // All other fields may need a `T: Deserialize` bound where T is the type of the
-// field.
+// field, including `rescript_result` fields, whose functions are generic over the
+// `Result`.
 fn needs_deserialize_bound(field: &attr::Field, variant: Option<&attr::Variant>) -> bool {
     !field.skip_deserializing()
-        && field.deserialize_with().is_none()
+        && (field.deserialize_with().is_none() || field.rescript_result())
         && field.de_bound().is_none()
         && variant.map_or(true, |variant| {
             !variant.skip_deserializing()
@@ -2,11 +2,13 @@ use crate::de::enum_adjacently;
 use crate::de::enum_externally;
 use crate::de::enum_internally;
 use crate::de::enum_mixed;
+use crate::de::enum_polymorphic;
+use crate::de::enum_unboxed;
 use crate::de::enum_untagged;
 use crate::de::identifier;
 use crate::de::{field_i, FieldWithAliases, Parameters};
 use crate::fragment::{Expr, Fragment, Stmts};
-use crate::internals::ast::{Style, Variant};
+use crate::internals::ast::Variant;
 use crate::internals::attr;
 use crate::private;
 use crate::rescript;
@@ -43,24 +45,50 @@ fn deserialize_homogeneous_enum(
     variants: &[Variant],
     cattrs: &attr::Container,
 ) -> Fragment {
-    // ReScript: detect mixed enums (both unit and struct variants)
-    let is_mixed = rescript::is_mixed_enum(variants);
+    let readable = deserialize_readable_enum(params, variants, cattrs);
+
+    // ReScript: formats that are not human-readable get the compact externally
+    // tagged encoding
+    if rescript::has_compact_fallback(cattrs, variants) {
+        let readable = Expr(readable);
+        let compact = Expr(enum_externally::deserialize(params, variants, cattrs));
+        quote_block! {
+            if _serde::Deserializer::is_human_readable(&__deserializer) {
+                #readable
+            } else {
+                #compact
+            }
+        }
+    } else {
+        readable
+    }
+}
+
+// Representation used by human-readable formats, which is what ReScript reads
+fn deserialize_readable_enum(
+    params: &Parameters,
+    variants: &[Variant],
+    cattrs: &attr::Container,
+) -> Fragment {
+    // ReScript: enums with payload variants, mixed with unit variants or not,
+    // are read by `enum_mixed`
+    let has_payload = variants.iter().any(rescript::has_payload);
 
     match cattrs.tag() {
         attr::TagType::External => {
-            // ReScript: handle mixed and struct-only enums specially
-            if is_mixed {
+            // ReScript: handle polymorphic, mixed and payload-only enums specially
+            if cattrs.polymorphic() {
+                enum_polymorphic::deserialize(params, variants, cattrs)
+            } else if has_payload || rescript::has_int_variants(variants) {
+                // Variants with payload use internal tagging with TAG, unit
+                // variants are bare strings
                 enum_mixed::deserialize(params, variants, cattrs)
-            } else if variants.iter().any(|v| matches!(v.style, Style::Struct)) {
-                // Non-mixed struct variants use internal tagging with TAG
-                let tag = rescript::get_effective_tag(cattrs);
-                enum_internally::deserialize(params, variants, cattrs, &tag)
             } else {
                 enum_externally::deserialize(params, variants, cattrs)
             }
         }
         attr::TagType::Internal { tag } => {
-            if is_mixed {
+            if has_payload {
                 enum_mixed::deserialize(params, variants, cattrs)
             } else {
                 enum_internally::deserialize(params, variants, cattrs, tag)
@@ -69,6 +97,11 @@ fn deserialize_homogeneous_enum(
         attr::TagType::Adjacent { tag, content } => {
             enum_adjacently::deserialize(params, variants, cattrs, tag, content)
         }
+        // ReScript: unboxed enums dispatch on the kind of the value instead of
+        // trying every variant
+        attr::TagType::None if cattrs.unboxed() => {
+            enum_unboxed::deserialize(params, variants, cattrs)
+        }
         attr::TagType::None => enum_untagged::deserialize(params, variants, cattrs, None),
     }
 }
@@ -114,3 +147,23 @@ pub fn prepare_enum_variant_enum(variants: &[Variant]) -> (TokenStream, Stmts) {
 
     (variants_stmt, variant_visitor)
 }
+
+/// ReScript: like `prepare_enum_variant_enum`, but for enums with typed tag
+/// values (`#[serde(tag_value = ...)]`) or mixed enums, where only the given
+/// variants are identified by the tag field. Unknown values are reported
+/// against `expecting`.
+pub fn prepare_tag_value_enum(variants: &[(usize, &Variant)], expecting: &str) -> Stmts {
+    let fallthrough = variants
+        .iter()
+        .find(|(_i, variant)| variant.attrs.other())
+        .map(|(i, _variant)| {
+            let ignore_variant = field_i(*i);
+            quote!(_serde::#private::Ok(__Field::#ignore_variant))
+        });
+
+    Stmts(identifier::deserialize_tag_value(
+        variants,
+        fallthrough,
+        expecting,
+    ))
+}
@@ -6,7 +6,6 @@
 //! ```
 
 use crate::de::enum_;
-use crate::de::enum_untagged;
 use crate::de::struct_;
 use crate::de::{
     effective_style, expr_is_missing, field_i, unwrap_to_variant_closure, Parameters, StructForm,
@@ -15,6 +14,7 @@ use crate::fragment::{Expr, Fragment, Match};
 use crate::internals::ast::{Style, Variant};
 use crate::internals::attr;
 use crate::private;
+use crate::rescript;
 use quote::quote;
 
 /// Generates `Deserialize::deserialize` body for an `enum Enum {...}` with `#[serde(tag)]` attribute
@@ -24,7 +24,25 @@ pub(super) fn deserialize(
     cattrs: &attr::Container,
     tag: &str,
 ) -> Fragment {
-    let (variants_stmt, variant_visitor) = enum_::prepare_enum_variant_enum(variants);
+    let expecting = format!("internally tagged enum {}", params.type_name());
+    let expecting = cattrs.expecting().unwrap_or(&expecting);
+
+    // ReScript: typed tag values need a visitor that accepts more than strings
+    let (variants_stmt, variant_visitor) = if rescript::has_tag_values(variants) {
+        let deserialized_variants: Vec<_> = variants
+            .iter()
+            .enumerate()
+            .filter(|&(_, variant)| !variant.attrs.skip_deserializing())
+            .collect();
+        let tag_expecting =
+            rescript::describe_tag(tag, &params.type_name(), &deserialized_variants);
+        (
+            quote!(),
+            enum_::prepare_tag_value_enum(&deserialized_variants, &tag_expecting),
+        )
+    } else {
+        enum_::prepare_enum_variant_enum(variants)
+    };
 
     // Match arms to extract a variant from a string
     let variant_arms = variants
@@ -43,9 +61,6 @@ pub(super) fn deserialize(
             }
         });
 
-    let expecting = format!("internally tagged enum {}", params.type_name());
-    let expecting = cattrs.expecting().unwrap_or(&expecting);
-
     quote_block! {
         #variant_visitor
 
@@ -64,7 +79,8 @@ pub(super) fn deserialize(
 
 // Generates significant part of the visit_seq and visit_map bodies of visitors
 // for the variants of internally tagged enum.
-fn deserialize_internally_tagged_variant(
+// Also used by ReScript mixed enums
+pub(super) fn deserialize_internally_tagged_variant(
     params: &Parameters,
     variant: &Variant,
     cattrs: &attr::Container,
@@ -92,15 +108,13 @@ fn deserialize_internally_tagged_variant(
                 _serde::#private::Ok(#this_value::#variant_ident #default)
             }
         }
-        Style::Newtype => {
-            enum_untagged::deserialize_newtype_variant(variant_ident, params, &variant.fields[0])
-        }
-        Style::Struct => struct_::deserialize(
+        // ReScript: tuple and newtype payloads are read from the positional
+        // keys (`_0`, `_1`, ...) next to the tag
+        Style::Struct | Style::Tuple | Style::Newtype => struct_::deserialize(
             params,
             &variant.fields,
             cattrs,
             StructForm::InternallyTagged(variant_ident),
         ),
-        Style::Tuple => unreachable!("checked in serde_derive_internals"),
     }
 }
@@ -1,19 +1,29 @@
-//! Deserialization for ReScript-style mixed enums (unit + struct variants).
+//! Deserialization for ReScript-style enums with payload, mixed with unit
+//! variants or not.
 //!
-//! Mixed enums are deserialized as:
-//! - Unit variants: plain strings like `"VariantName"`
+//! Such enums are deserialized as:
+//! - Unit variants: plain strings like `"VariantName"`, or numbers for variants
+//!   with `#[serde(as_int = 1)]`
 //! - Struct variants: objects with TAG field like `{ "TAG": "VariantName", "field": value }`
-
-use crate::de::struct_;
-use crate::de::{field_i, Parameters, StructForm};
-use crate::fragment::{Fragment, Match};
+//! - Tuple and newtype variants: objects with TAG field and positional keys like
+//!   `{ "TAG": "VariantName", "_0": value, "_1": value }`
+//!
+//! Variant aliases are accepted both as strings and as TAG values, anything
+//! unknown is read as the `#[serde(other)]` variant if there is one. Errors
+//! list every accepted value: `enum Shape: "Empty" | {"TAG": "Circle" | "Rect"}`.
+
+use crate::de::enum_;
+use crate::de::enum_internally;
+use crate::de::{effective_style, expr_is_missing, field_i, Parameters};
+use crate::fragment::{Expr, Fragment, Match};
 use crate::internals::ast::{Style, Variant};
 use crate::internals::attr;
 use crate::private;
 use crate::rescript;
+use proc_macro2::TokenStream;
 use quote::quote;
 
-/// Generates `Deserialize::deserialize` body for a mixed enum (unit + struct variants)
+/// Generates `Deserialize::deserialize` body for a mixed enum (unit variants + variants with payload)
 pub(super) fn deserialize(
     params: &Parameters,
     variants: &[Variant],
@@ -25,160 +35,239 @@ pub(super) fn deserialize(
         params.generics_with_de_lifetime();
     let delife = params.borrowed.de_lifetime();
 
-    let expecting = format!("mixed enum {}", params.type_name());
-    let expecting = cattrs.expecting().unwrap_or(&expecting);
-
+    let type_name = params.type_name();
     let tag = rescript::get_effective_tag(cattrs);
 
-    // Unit variants - deserialize from strings
+    // Unit variants - deserialize from strings. Newtype variants with a skipped
+    // field are serialized as unit variants, so they are accepted here as well.
     let unit_variants: Vec<_> = variants
         .iter()
         .enumerate()
-        .filter(|(_, v)| !v.attrs.skip_deserializing() && matches!(v.style, Style::Unit))
+        .filter(|(_, v)| {
+            !v.attrs.skip_deserializing()
+                && v.attrs.as_int().is_none()
+                && matches!(effective_style(v), Style::Unit)
+        })
         .collect();
 
     let unit_variant_arms: Vec<_> = unit_variants
         .iter()
         .map(|(_, variant)| {
-            let variant_name = variant.attrs.name().deserialize_name();
+            // `aliases` also contains a main name
+            let aliases = variant.attrs.aliases();
             let variant_ident = &variant.ident;
+            let default = variant.fields.first().map(|field| {
+                let default = Expr(expr_is_missing(field, cattrs));
+                quote!((#default))
+            });
             quote! {
-                #variant_name => _serde::#private::Ok(#this_value::#variant_ident),
+                #(#aliases)|* => _serde::#private::Ok(#this_value::#variant_ident #default),
             }
         })
         .collect();
 
-    let unit_variant_names: Vec<_> = unit_variants
+    // Unknown strings, numbers and tags fall back to the `#[serde(other)]`
+    // variant, if any
+    let other_variant = variants
         .iter()
-        .map(|(_, v)| v.attrs.name().deserialize_name())
+        .enumerate()
+        .find(|(_, v)| !v.attrs.skip_deserializing() && v.attrs.other());
+    let fallthrough = other_variant.map(|(_, variant)| {
+        let variant_ident = &variant.ident;
+        quote!(_serde::#private::Ok(#this_value::#variant_ident))
+    });
+
+    // Unit variants represented by numbers
+    let int_variant_arms: Vec<_> = variants
+        .iter()
+        .filter(|v| !v.attrs.skip_deserializing())
+        .filter_map(|variant| {
+            let value = variant.attrs.as_int()?;
+            let variant_ident = &variant.ident;
+            Some(quote! {
+                #value => _serde::#private::Ok(#this_value::#variant_ident),
+            })
+        })
         .collect();
 
-    // Struct variants
-    let struct_variants: Vec<_> = variants
+    // Variants with payload: struct, tuple and newtype
+    let payload_variants: Vec<_> = variants
         .iter()
         .enumerate()
-        .filter(|(_, v)| !v.attrs.skip_deserializing() && matches!(v.style, Style::Struct))
+        .filter(|(_, v)| !v.attrs.skip_deserializing() && rescript::has_payload(v))
         .collect();
 
-    // Generate variant enum for struct variants
-    let struct_variant_field_idents: Vec<_> =
-        struct_variants.iter().map(|(i, _)| field_i(*i)).collect();
-
-    let struct_variant_names: Vec<_> = struct_variants
+    // Generate variant enum for payload variants (used to deserialize the TAG
+    // value, which is a string or the typed `tag_value` of the variant). The
+    // `other` variant catches unknown tags.
+    let tagged_variants: Vec<_> = payload_variants
         .iter()
-        .map(|(_, v)| v.attrs.name().deserialize_name())
+        .copied()
+        .chain(other_variant)
         .collect();
+    let variant_visitor = if payload_variants.is_empty() {
+        None
+    } else {
+        let tag_expecting = rescript::describe_tag(&tag, &type_name, &tagged_variants);
+        Some(enum_::prepare_tag_value_enum(
+            &tagged_variants,
+            &tag_expecting,
+        ))
+    };
 
-    // Build variant enum match arms
-    let variant_match_arms: Vec<_> = struct_variants
+    // Errors list every accepted value, i.e.
+    // `enum Shape: "Empty" | 1 | {"TAG": "Circle" | "Rect"}`
+    let mut alternatives: Vec<_> = unit_variants
         .iter()
-        .map(|(i, v)| {
-            let name = v.attrs.name().deserialize_name();
-            let ident = field_i(*i);
-            quote! { #name => _serde::#private::Ok(__Field::#ident) }
-        })
+        .map(|(_, variant)| format!("{:?}", variant.attrs.name().deserialize_name().value))
         .collect();
+    alternatives.extend(
+        variants
+            .iter()
+            .filter(|v| !v.attrs.skip_deserializing())
+            .filter_map(|v| v.attrs.as_int())
+            .map(|value| value.to_string()),
+    );
+    if !payload_variants.is_empty() {
+        alternatives.push(format!(
+            "{{{:?}: {}}}",
+            tag,
+            rescript::tag_values(&payload_variants)
+        ));
+    }
+    let expecting = format!("enum {}: {}", type_name, alternatives.join(" | "));
+    let expecting = cattrs.expecting().unwrap_or(&expecting);
 
-    // Generate variant enum for struct variants (used to deserialize the TAG value)
-    let field_enum = if struct_variants.is_empty() {
-        quote! {}
-    } else {
-        quote! {
-            #[doc(hidden)]
-            enum __Field {
-                #(#struct_variant_field_idents,)*
-            }
-
-            impl<#delife> _serde::Deserialize<#delife> for __Field {
-                fn deserialize<__D>(__deserializer: __D) -> _serde::#private::Result<Self, __D::Error>
-                where
-                    __D: _serde::Deserializer<#delife>,
-                {
-                    struct __FieldVisitor;
-
-                    impl<#delife> _serde::de::Visitor<#delife> for __FieldVisitor {
-                        type Value = __Field;
-
-                        fn expecting(&self, __f: &mut _serde::#private::Formatter) -> _serde::#private::fmt::Result {
-                            _serde::#private::Formatter::write_str(__f, "variant identifier")
-                        }
-
-                        fn visit_str<__E>(self, __value: &str) -> _serde::#private::Result<Self::Value, __E>
-                        where
-                            __E: _serde::de::Error,
-                        {
-                            match __value {
-                                #(#variant_match_arms,)*
-                                _ => _serde::#private::Err(_serde::de::Error::unknown_variant(__value, &[#(#struct_variant_names),*])),
-                            }
-                        }
-                    }
-
-                    _serde::Deserializer::deserialize_identifier(__deserializer, __FieldVisitor)
-                }
-            }
-        }
-    };
+    // Payload variants written the way upstream serde tags enums,
+    // `{"Circle": {...}}`, get a hint
+    let payload_names: Vec<_> = payload_variants
+        .iter()
+        .map(|(_, variant)| &variant.attrs.name().deserialize_name().value)
+        .collect();
 
-    // Generate match arms for struct variants using struct_::deserialize
-    let struct_variant_arms: Vec<_> = struct_variants
+    // Generate match arms for payload variants the same way internally tagged
+    // enums do, tuple and newtype payloads are keyed by position (`_0`, `_1`, ...)
+    let payload_variant_arms: Vec<_> = payload_variants
         .iter()
         .map(|(i, variant)| {
             let variant_field = field_i(*i);
-            let variant_ident = &variant.ident;
 
-            let block = Match(struct_::deserialize(
-                params,
-                &variant.fields,
-                cattrs,
-                StructForm::InternallyTagged(variant_ident),
+            let block = Match(enum_internally::deserialize_internally_tagged_variant(
+                params, variant, cattrs,
             ));
 
             quote! {
                 __Field::#variant_field => #block
             }
         })
+        .chain(other_variant.map(|(i, variant)| {
+            let variant_field = field_i(i);
+            let variant_ident = &variant.ident;
+            // The payload of an unknown variant is skipped
+            quote! {
+                __Field::#variant_field => {
+                    _serde::Deserializer::deserialize_any(__deserializer, _serde::de::IgnoredAny)?;
+                    _serde::#private::Ok(#this_value::#variant_ident)
+                }
+            }
+        }))
         .collect();
 
+    let unknown_str = match &fallthrough {
+        Some(fallthrough) => fallthrough.clone(),
+        None => quote! {
+            _serde::#private::Err(_serde::de::Error::invalid_value(_serde::de::Unexpected::Str(__value), &self))
+        },
+    };
+
     // For unit variants from string
     let visit_str_body = if unit_variants.is_empty() {
         quote! {
-            _serde::#private::Err(_serde::de::Error::custom("expected object with TAG field"))
+            _serde::#private::Err(_serde::de::Error::invalid_type(_serde::de::Unexpected::Str(__value), &self))
         }
     } else {
         quote! {
             match __value {
                 #(#unit_variant_arms)*
-                _ => {
-                    let __expected = &[#(#unit_variant_names),*];
-                    _serde::#private::Err(_serde::de::Error::unknown_variant(__value, __expected))
-                }
+                _ => #unknown_str,
             }
         }
     };
 
-    // For struct variants from map: use TaggedContentVisitor pattern
-    let visit_map_body = if struct_variants.is_empty() {
+    let unknown_int = |unexpected: TokenStream| match &fallthrough {
+        Some(fallthrough) => fallthrough.clone(),
+        None => quote! {
+            _serde::#private::Err(_serde::de::Error::invalid_value(#unexpected, &self))
+        },
+    };
+    let unknown_signed = unknown_int(quote!(_serde::de::Unexpected::Signed(__value)));
+    let unknown_unsigned = unknown_int(quote!(_serde::de::Unexpected::Unsigned(__value)));
+
+    let visit_int = if int_variant_arms.is_empty() {
+        None
+    } else {
+        Some(quote! {
+            fn visit_i64<__E>(self, __value: i64) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                match __value {
+                    #(#int_variant_arms)*
+                    _ => #unknown_signed,
+                }
+            }
+
+            fn visit_u64<__E>(self, __value: u64) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                if __value <= ::core::primitive::i64::MAX as u64 {
+                    _serde::de::Visitor::visit_i64(self, __value as i64)
+                } else {
+                    #unknown_unsigned
+                }
+            }
+        })
+    };
+
+    // For payload variants from map: use TaggedContentVisitor pattern
+    let visit_map_body = if payload_variants.is_empty() {
         quote! {
-            _serde::#private::Err(_serde::de::Error::custom("expected string for unit variant"))
+            _serde::#private::Err(_serde::de::Error::invalid_type(_serde::de::Unexpected::Map, &self))
         }
     } else {
         quote! {
-            // Collect entries and extract TAG field (similar to TaggedContentVisitor)
+            let __is_tag = |__key: &_serde::#private::de::Content| {
+                _serde::#private::de::content_as_str(__key) == _serde::#private::Some(#tag)
+            };
+
             let mut __tag: _serde::#private::Option<__Field> = _serde::#private::None;
             let mut __vec = _serde::#private::Vec::<(
                 _serde::#private::de::Content<#delife>,
                 _serde::#private::de::Content<#delife>,
             )>::new();
 
+            // ReScript writes the tag first, in which case the payload is read
+            // straight from the map without buffering. A repeated tag is
+            // rejected by `MapAfterTag`
+            if let _serde::#private::Some(__key) = _serde::de::MapAccess::next_key_seed(&mut __map, _serde::#private::de::ContentVisitor::new())? {
+                if __is_tag(&__key) {
+                    let __tag: __Field = _serde::de::MapAccess::next_value(&mut __map)?;
+                    let __deserializer = _serde::de::value::MapAccessDeserializer::new(
+                        _serde::#private::de::MapAfterTag::new(__map, #tag),
+                    );
+                    return match __tag {
+                        #(#payload_variant_arms)*
+                    };
+                }
+                let __v = _serde::de::MapAccess::next_value_seed(&mut __map, _serde::#private::de::ContentVisitor::new())?;
+                __vec.push((__key, __v));
+            }
+
+            // Otherwise collect entries and extract TAG field (similar to
+            // TaggedContentVisitor)
             while let _serde::#private::Some(__key) = _serde::de::MapAccess::next_key_seed(&mut __map, _serde::#private::de::ContentVisitor::new())? {
-                // Check if this key matches the tag field
-                let __is_tag = match &__key {
-                    _serde::#private::de::Content::String(__s) => __s == #tag,
-                    _serde::#private::de::Content::Str(__s) => *__s == #tag,
-                    _ => false,
-                };
-                if __is_tag {
+                if __is_tag(&__key) {
                     if __tag.is_some() {
                         return _serde::#private::Err(_serde::de::Error::duplicate_field(#tag));
                     }
@@ -191,7 +280,25 @@ pub(super) fn deserialize(
 
             let __tag = match __tag {
                 _serde::#private::Some(__t) => __t,
-                _serde::#private::None => return _serde::#private::Err(_serde::de::Error::missing_field(#tag)),
+                _serde::#private::None => {
+                    let __key = match __vec.as_slice() {
+                        [(_serde::#private::de::Content::String(__key), _)] => _serde::#private::Some(__key.as_str()),
+                        [(_serde::#private::de::Content::Str(__key), _)] => _serde::#private::Some(*__key),
+                        _ => _serde::#private::None,
+                    };
+                    return _serde::#private::Err(match __key {
+                        _serde::#private::Some(__key) if [#(#payload_names),*].contains(&__key) => {
+                            _serde::de::Error::custom(_serde::#private::format_args!(
+                                "missing field `{}`, expected {} (found `{{\"{}\": ...}}`, the way upstream serde tags enums)",
+                                #tag, #expecting, __key,
+                            ))
+                        }
+                        _ => _serde::de::Error::custom(_serde::#private::format_args!(
+                            "missing field `{}`, expected {}",
+                            #tag, #expecting,
+                        )),
+                    });
+                }
             };
 
             // Create ContentDeserializer from the remaining fields
@@ -199,13 +306,13 @@ pub(super) fn deserialize(
             let __deserializer = _serde::#private::de::ContentDeserializer::<__A::Error>::new(__content);
 
             match __tag {
-                #(#struct_variant_arms)*
+                #(#payload_variant_arms)*
             }
         }
     };
 
     quote_block! {
-        #field_enum
+        #variant_visitor
 
         #[doc(hidden)]
         struct __Visitor #de_impl_generics #where_clause {
@@ -227,6 +334,8 @@ pub(super) fn deserialize(
                 #visit_str_body
             }
 
+            #visit_int
+
             fn visit_map<__A>(self, mut __map: __A) -> _serde::#private::Result<Self::Value, __A::Error>
             where
                 __A: _serde::de::MapAccess<#delife>,
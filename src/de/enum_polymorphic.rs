@@ -0,0 +1,103 @@
+//! Deserialization for ReScript polymorphic variants:
+//!
+//! ```ignore
+//! #[serde(polymorphic)]
+//! enum Enum {}
+//! ```
+//!
+//! All variants are unit variants, read from plain strings through
+//! `Deserializer::deserialize_str`.
+
+use crate::de::Parameters;
+use crate::fragment::Fragment;
+use crate::internals::ast::Variant;
+use crate::internals::attr;
+use crate::private;
+use quote::quote;
+
+/// Generates `Deserialize::deserialize` body for an `enum Enum {...}` with `#[serde(polymorphic)]` attribute
+pub(super) fn deserialize(
+    params: &Parameters,
+    variants: &[Variant],
+    cattrs: &attr::Container,
+) -> Fragment {
+    let this_type = &params.this_type;
+    let this_value = &params.this_value;
+    let (de_impl_generics, de_ty_generics, ty_generics, where_clause) =
+        params.generics_with_de_lifetime();
+    let delife = params.borrowed.de_lifetime();
+
+    let expecting = format!("polymorphic variant {}", params.type_name());
+    let expecting = cattrs.expecting().unwrap_or(&expecting);
+
+    let deserialized_variants = variants
+        .iter()
+        .filter(|variant| !variant.attrs.skip_deserializing());
+
+    let variant_arms = deserialized_variants
+        .clone()
+        .filter(|variant| !variant.attrs.other())
+        .map(|variant| {
+            let aliases = variant.attrs.aliases();
+            let variant_ident = &variant.ident;
+            quote! {
+                #(#aliases)|* => _serde::#private::Ok(#this_value::#variant_ident),
+            }
+        });
+
+    let variant_names = deserialized_variants
+        .clone()
+        .flat_map(|variant| variant.attrs.aliases());
+
+    let fallthrough = match deserialized_variants
+        .clone()
+        .find(|variant| variant.attrs.other())
+    {
+        Some(other) => {
+            let variant_ident = &other.ident;
+            quote! {
+                _ => _serde::#private::Ok(#this_value::#variant_ident),
+            }
+        }
+        None => quote! {
+            _ => _serde::#private::Err(_serde::de::Error::unknown_variant(__value, VARIANTS)),
+        },
+    };
+
+    quote_block! {
+        #[doc(hidden)]
+        const VARIANTS: &'static [&'static str] = &[ #(#variant_names),* ];
+
+        #[doc(hidden)]
+        struct __Visitor #de_impl_generics #where_clause {
+            marker: _serde::#private::PhantomData<#this_type #ty_generics>,
+            lifetime: _serde::#private::PhantomData<&#delife ()>,
+        }
+
+        impl #de_impl_generics _serde::de::Visitor<#delife> for __Visitor #de_ty_generics #where_clause {
+            type Value = #this_type #ty_generics;
+
+            fn expecting(&self, __formatter: &mut _serde::#private::Formatter) -> _serde::#private::fmt::Result {
+                _serde::#private::Formatter::write_str(__formatter, #expecting)
+            }
+
+            fn visit_str<__E>(self, __value: &str) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                match __value {
+                    #(#variant_arms)*
+                    #fallthrough
+                }
+            }
+        }
+
+        _serde::Deserializer::deserialize_str(
+            __deserializer,
+            __Visitor {
+                marker: _serde::#private::PhantomData::<#this_type #ty_generics>,
+                lifetime: _serde::#private::PhantomData,
+            },
+        )
+    }
+}
@@ -0,0 +1,179 @@
+//! Deserialization for ReScript `@unboxed` enums:
+//!
+//! ```ignore
+//! #[serde(unboxed)]
+//! enum Enum {}
+//! ```
+//!
+//! Unlike untagged enums, the variant is picked by the kind of the value
+//! (null, boolean, number, string, array or object) without buffering it, the
+//! same way the ReScript runtime tells unboxed constructors apart.
+
+use crate::de::enum_untagged;
+use crate::de::{expr_is_missing, Parameters};
+use crate::fragment::{Expr, Fragment};
+use crate::internals::ast::Variant;
+use crate::internals::attr;
+use crate::private;
+use crate::rescript::{self, PayloadKind};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Generates `Deserialize::deserialize` body for an `enum Enum {...}` with `#[serde(unboxed)]` attribute
+pub(super) fn deserialize(
+    params: &Parameters,
+    variants: &[Variant],
+    cattrs: &attr::Container,
+) -> Fragment {
+    let this_type = &params.this_type;
+    let (de_impl_generics, de_ty_generics, ty_generics, where_clause) =
+        params.generics_with_de_lifetime();
+    let delife = params.borrowed.de_lifetime();
+
+    let expecting = format!("unboxed enum {}", params.type_name());
+    let expecting = cattrs.expecting().unwrap_or(&expecting);
+
+    // Payload kinds are checked to be unique in `internals::check`
+    let variant_of = |kind: PayloadKind| {
+        variants.iter().find(|variant| {
+            !variant.attrs.skip_deserializing() && rescript::payload_kind(variant) == Some(kind)
+        })
+    };
+    let deserialize_payload = |kind: PayloadKind| -> Option<TokenStream> {
+        let variant = variant_of(kind)?;
+        let block = Expr(enum_untagged::deserialize_variant(params, variant, cattrs));
+        Some(quote!(#block))
+    };
+
+    let visit_null = variant_of(PayloadKind::Null).map(|variant| {
+        let this_value = &params.this_value;
+        let variant_ident = &variant.ident;
+        let default = variant.fields.first().map(|field| {
+            let default = Expr(expr_is_missing(field, cattrs));
+            quote!((#default))
+        });
+        quote! {
+            fn visit_unit<__E>(self) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                _serde::#private::Ok(#this_value::#variant_ident #default)
+            }
+
+            fn visit_none<__E>(self) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                _serde::de::Visitor::visit_unit(self)
+            }
+        }
+    });
+
+    let visit_bool = deserialize_payload(PayloadKind::Bool).map(|block| {
+        quote! {
+            fn visit_bool<__E>(self, __value: bool) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                let __deserializer = _serde::de::IntoDeserializer::<#delife, __E>::into_deserializer(__value);
+                #block
+            }
+        }
+    });
+
+    let visit_number = deserialize_payload(PayloadKind::Number).map(|block| {
+        let visit = [
+            (quote!(visit_i64), quote!(i64)),
+            (quote!(visit_u64), quote!(u64)),
+            (quote!(visit_f64), quote!(f64)),
+        ]
+        .into_iter()
+        .map(|(method, ty)| {
+            quote! {
+                fn #method<__E>(self, __value: #ty) -> _serde::#private::Result<Self::Value, __E>
+                where
+                    __E: _serde::de::Error,
+                {
+                    let __deserializer = _serde::de::IntoDeserializer::<#delife, __E>::into_deserializer(__value);
+                    #block
+                }
+            }
+        });
+        quote!(#(#visit)*)
+    });
+
+    let visit_string = deserialize_payload(PayloadKind::String).map(|block| {
+        quote! {
+            fn visit_str<__E>(self, __value: &str) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                let __deserializer = _serde::de::value::StrDeserializer::<__E>::new(__value);
+                #block
+            }
+
+            fn visit_borrowed_str<__E>(self, __value: &#delife str) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                let __deserializer = _serde::de::value::BorrowedStrDeserializer::<__E>::new(__value);
+                #block
+            }
+        }
+    });
+
+    let visit_seq = deserialize_payload(PayloadKind::Array).map(|block| {
+        quote! {
+            fn visit_seq<__A>(self, __seq: __A) -> _serde::#private::Result<Self::Value, __A::Error>
+            where
+                __A: _serde::de::SeqAccess<#delife>,
+            {
+                let __deserializer = _serde::de::value::SeqAccessDeserializer::new(__seq);
+                #block
+            }
+        }
+    });
+
+    let visit_map = deserialize_payload(PayloadKind::Object).map(|block| {
+        quote! {
+            fn visit_map<__A>(self, __map: __A) -> _serde::#private::Result<Self::Value, __A::Error>
+            where
+                __A: _serde::de::MapAccess<#delife>,
+            {
+                let __deserializer = _serde::de::value::MapAccessDeserializer::new(__map);
+                #block
+            }
+        }
+    });
+
+    quote_block! {
+        #[doc(hidden)]
+        struct __Visitor #de_impl_generics #where_clause {
+            marker: _serde::#private::PhantomData<#this_type #ty_generics>,
+            lifetime: _serde::#private::PhantomData<&#delife ()>,
+        }
+
+        impl #de_impl_generics _serde::de::Visitor<#delife> for __Visitor #de_ty_generics #where_clause {
+            type Value = #this_type #ty_generics;
+
+            fn expecting(&self, __formatter: &mut _serde::#private::Formatter) -> _serde::#private::fmt::Result {
+                _serde::#private::Formatter::write_str(__formatter, #expecting)
+            }
+
+            #visit_null
+            #visit_bool
+            #visit_number
+            #visit_string
+            #visit_seq
+            #visit_map
+        }
+
+        _serde::Deserializer::deserialize_any(
+            __deserializer,
+            __Visitor {
+                marker: _serde::#private::PhantomData::<#this_type #ty_generics>,
+                lifetime: _serde::#private::PhantomData,
+            },
+        )
+    }
+}
@@ -1,11 +1,11 @@
 //! Deserialization of struct field identifiers and enum variant identifiers by
 //! way of a Rust enum.
 
-use crate::de::{FieldWithAliases, Parameters};
+use crate::de::{field_i, FieldWithAliases, Parameters};
 use crate::fragment::{Fragment, Stmts};
 use crate::internals::ast::{Style, Variant};
 use crate::internals::attr;
-use crate::private;
+use crate::{private, rescript};
 use proc_macro2::{Literal, TokenStream};
 use quote::{quote, ToTokens};
 
@@ -182,6 +182,233 @@ pub(super) fn deserialize_generated(
     }
 }
 
+// ReScript: generates the `__Field` enum identifying a variant by the value of
+// its tag field. Variants with `#[serde(tag_value = ...)]` are matched by the
+// bool, number or null literal, the rest by name and aliases. Unlike other
+// identifiers the tag is read with `deserialize_any`, so the format reports the
+// actual type of the value, and unknown values of any kind are reported against
+// `expecting`, which lists the accepted ones.
+pub(super) fn deserialize_tag_value(
+    variants: &[(usize, &Variant)],
+    fallthrough: Option<TokenStream>,
+    expecting: &str,
+) -> Fragment {
+    let this_value = quote!(__Field);
+    let field_idents: Vec<_> = variants.iter().map(|(i, _)| field_i(*i)).collect();
+
+    let mut str_mapping = Vec::new();
+    let mut bool_mapping = Vec::new();
+    let mut int_mapping = Vec::new();
+    let mut float_mapping = Vec::new();
+    let mut null_mapping = None;
+    for (i, variant) in variants {
+        let ident = field_i(*i);
+        match variant.attrs.tag_value() {
+            None => {
+                // `aliases` also contains a main name
+                let aliases = variant.attrs.aliases();
+                let private2 = private;
+                str_mapping.push(quote! {
+                    #(
+                        #aliases => _serde::#private2::Ok(#this_value::#ident),
+                    )*
+                });
+            }
+            Some(attr::TagValue::Bool(value)) => bool_mapping.push(quote! {
+                if __value == #value {
+                    return _serde::#private::Ok(#this_value::#ident);
+                }
+            }),
+            // A number is read as an integer or a float depending on how it
+            // is written, and JavaScript writes `1.0` as `1`, so tags are
+            // matched in both forms
+            Some(attr::TagValue::Int(value)) => {
+                let float = value as f64;
+                int_mapping.push(quote! {
+                    if __value == #value {
+                        return _serde::#private::Ok(#this_value::#ident);
+                    }
+                });
+                float_mapping.push(quote! {
+                    if __value == #float {
+                        return _serde::#private::Ok(#this_value::#ident);
+                    }
+                });
+            }
+            Some(attr::TagValue::Float(value)) => {
+                if let Some(int) = rescript::integral(value) {
+                    int_mapping.push(quote! {
+                        if __value == #int {
+                            return _serde::#private::Ok(#this_value::#ident);
+                        }
+                    });
+                }
+                float_mapping.push(quote! {
+                    if __value == #value {
+                        return _serde::#private::Ok(#this_value::#ident);
+                    }
+                });
+            }
+            Some(attr::TagValue::Null) => {
+                null_mapping = Some(quote!(_serde::#private::Ok(#this_value::#ident)));
+            }
+        }
+    }
+
+    let fallthrough_or = |unexpected: TokenStream| match &fallthrough {
+        Some(fallthrough) => fallthrough.clone(),
+        None => quote! {
+            _serde::#private::Err(_serde::de::Error::invalid_value(#unexpected, &self))
+        },
+    };
+
+    let str_fallthrough = fallthrough_or(quote!(_serde::de::Unexpected::Str(__value)));
+
+    // Strings, bytes, booleans and integers are always accepted, so a tag of
+    // the wrong kind is reported as an unknown value rather than a wrong type
+    let bytes_mapping = variants
+        .iter()
+        .filter(|(_, variant)| variant.attrs.tag_value().is_none())
+        .map(|(i, variant)| {
+            let ident = field_i(*i);
+            let aliases = variant
+                .attrs
+                .aliases()
+                .iter()
+                .map(|alias| Literal::byte_string(alias.value.as_bytes()));
+            let private2 = private;
+            quote! {
+                #(
+                    #aliases => _serde::#private2::Ok(#this_value::#ident),
+                )*
+            }
+        });
+    let bytes_fallthrough = match &fallthrough {
+        Some(fallthrough) => fallthrough.clone(),
+        None => quote! {{
+            let __value = &_serde::#private::from_utf8_lossy(__value);
+            _serde::#private::Err(_serde::de::Error::invalid_value(_serde::de::Unexpected::Str(__value), &self))
+        }},
+    };
+    let bool_fallthrough = fallthrough_or(quote!(_serde::de::Unexpected::Bool(__value)));
+    let int_fallthrough = fallthrough_or(quote!(_serde::de::Unexpected::Signed(__value)));
+    let unsigned_fallthrough = fallthrough_or(quote!(_serde::de::Unexpected::Unsigned(__value)));
+
+    let visit_float = if float_mapping.is_empty() {
+        None
+    } else {
+        let fallthrough = fallthrough_or(quote!(_serde::de::Unexpected::Float(__value)));
+        Some(quote! {
+            fn visit_f64<__E>(self, __value: f64) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                #(#float_mapping)*
+                #fallthrough
+            }
+        })
+    };
+
+    let visit_null = null_mapping.map(|mapping| {
+        quote! {
+            fn visit_unit<__E>(self) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                #mapping
+            }
+
+            fn visit_none<__E>(self) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                #mapping
+            }
+        }
+    });
+
+    quote_block! {
+        #[allow(non_camel_case_types)]
+        #[doc(hidden)]
+        enum __Field {
+            #(#field_idents,)*
+        }
+
+        #[doc(hidden)]
+        struct __FieldVisitor;
+
+        #[automatically_derived]
+        impl<'de> _serde::de::Visitor<'de> for __FieldVisitor {
+            type Value = __Field;
+
+            fn expecting(&self, __formatter: &mut _serde::#private::Formatter) -> _serde::#private::fmt::Result {
+                _serde::#private::Formatter::write_str(__formatter, #expecting)
+            }
+
+            fn visit_str<__E>(self, __value: &str) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                match __value {
+                    #(#str_mapping)*
+                    _ => #str_fallthrough,
+                }
+            }
+
+            fn visit_bytes<__E>(self, __value: &[u8]) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                match __value {
+                    #(#bytes_mapping)*
+                    _ => #bytes_fallthrough,
+                }
+            }
+
+            fn visit_bool<__E>(self, __value: bool) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                #(#bool_mapping)*
+                #bool_fallthrough
+            }
+
+            fn visit_i64<__E>(self, __value: i64) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                #(#int_mapping)*
+                #int_fallthrough
+            }
+
+            fn visit_u64<__E>(self, __value: u64) -> _serde::#private::Result<Self::Value, __E>
+            where
+                __E: _serde::de::Error,
+            {
+                if __value <= ::core::primitive::i64::MAX as u64 {
+                    _serde::de::Visitor::visit_i64(self, __value as i64)
+                } else {
+                    #unsigned_fallthrough
+                }
+            }
+
+            #visit_float
+            #visit_null
+        }
+
+        #[automatically_derived]
+        impl<'de> _serde::Deserialize<'de> for __Field {
+            #[inline]
+            fn deserialize<__D>(__deserializer: __D) -> _serde::#private::Result<Self, __D::Error>
+            where
+                __D: _serde::Deserializer<'de>,
+            {
+                _serde::Deserializer::deserialize_any(__deserializer, __FieldVisitor)
+            }
+        }
+    }
+}
+
 fn deserialize_identifier(
     this_value: &TokenStream,
     deserialized_fields: &[FieldWithAliases],
@@ -9,6 +9,7 @@ use crate::fragment::{Expr, Fragment, Match, Stmts};
 use crate::internals::ast::Field;
 use crate::internals::attr;
 use crate::private;
+use crate::rescript;
 use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned};
 use syn::spanned::Spanned;
@@ -240,8 +241,20 @@ fn deserialize_map(
         .map(|(field, name)| {
             let deser_name = field.attrs.name().deserialize_name();
 
-            let visit = match field.attrs.deserialize_with() {
-                None => {
+            let visit = match (
+                field.attrs.deserialize_with(),
+                rescript::nullable_field_inner(field),
+            ) {
+                // ReScript: a present `Option<Option<T>>` field is `Some`, even
+                // if it is `null`, in human-readable formats
+                (None, Some(inner_ty)) => {
+                    let span = field.original.span();
+                    let func = quote_spanned!(span=> _serde::de::MapAccess::next_value_seed);
+                    quote! {
+                        #func(&mut __map, _serde::#private::de::NullableField::<#inner_ty>::new())?
+                    }
+                }
+                (None, None) => {
                     let field_ty = field.ty;
                     let span = field.original.span();
                     let func =
@@ -250,7 +263,7 @@ fn deserialize_map(
                         #func(&mut __map)?
                     }
                 }
-                Some(path) => {
+                (Some(path), _) => {
                     let (wrapper, wrapper_ty) = wrap_deserialize_field_with(params, field.ty, path);
                     quote!({
                         #wrapper
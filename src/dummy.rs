@@ -1,17 +1,36 @@
+use crate::internals::config;
 use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn wrap_in_const(serde_path: Option<&syn::Path>, code: TokenStream) -> TokenStream {
+    // ReScript: `_serde` is serde together with the private helpers of the
+    // runtime crate, which aren't tied to one serde release
     let use_serde = match serde_path {
         Some(path) => quote! {
-            use #path as _serde;
+            mod _serde {
+                pub use #path::*;
+                pub use ::serde_derive_rescript_rt::__serde::__private;
+            }
         },
         None => quote! {
             #[allow(unused_extern_crates, clippy::useless_attribute)]
-            extern crate serde as _serde;
+            extern crate serde_derive_rescript_rt as _serde_rt;
+            use _serde_rt::__serde as _serde;
         },
     };
 
+    // ReScript: crate-wide options are read from the crate's Cargo.toml during
+    // expansion, so it is included to rebuild the crate when it changes
+    let track_config = match config::load() {
+        Ok(Some(config)) => {
+            let path = config.manifest.to_string_lossy();
+            Some(quote!(
+                const _: &[u8] = include_bytes!(#path);
+            ))
+        }
+        _ => None,
+    };
+
     quote! {
         #[doc(hidden)]
         #[allow(
@@ -22,8 +41,7 @@ pub fn wrap_in_const(serde_path: Option<&syn::Path>, code: TokenStream) -> Token
         )]
         const _: () = {
             #use_serde
-
-            _serde::__require_serde_not_serde_core!();
+            #track_config
 
             #code
         };
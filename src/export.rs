@@ -0,0 +1,272 @@
+//! `RescriptType` derive: ReScript type definitions matching the JSON produced
+//! by `SerializeDto`.
+//!
+//! Every exported type becomes a module named after the Rust type, or
+//! `#[rescript(name = "...")]`, holding a `t` annotated with `@schema`:
+//!
+//! ```rescript
+//! module Person = {
+//!   @schema
+//!   type t = {
+//!     firstName: string,
+//!     age: int,
+//!   }
+//! }
+//! ```
+//!
+//! The derive also implements `serde_derive_rescript_rt::RescriptType`, so the
+//! modules of a type and of every type it refers to can be collected and
+//! written to `.res` files.
+//!
+//! Next to the module, `rescript_schema()` returns a sury schema expression for
+//! `t` spelling out the wire format, for the cases the `@schema` ppx gets
+//! wrong. Fields and variants with custom `serialize_with` are described by
+//! their Rust type, which is not collected, so their schema has to be adjusted
+//! by hand or given with `#[rescript(type = "...", schema = "...")]`.
+
+use crate::deprecated::allow_deprecated;
+use crate::internals::ast::{Container, Data, Field, Style, Variant};
+use crate::internals::{attr, replace_receiver, Ctxt, Derive, RenameRule};
+use crate::{dummy, private, rescript};
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ext::IdentExt as _;
+
+mod schema;
+mod ty;
+mod type_;
+
+pub fn expand_derive_rescript_type(input: &mut syn::DeriveInput) -> syn::Result<TokenStream> {
+    replace_receiver(input);
+
+    let ctxt = Ctxt::new();
+    let Some(cont) = Container::from_ast(&ctxt, input, Derive::RescriptType, &private.ident())
+    else {
+        return Err(ctxt.check().unwrap_err());
+    };
+    precondition(&ctxt, &cont);
+    ctxt.check()?;
+
+    let ident = &cont.ident;
+    let (impl_generics, ty_generics, where_clause) = cont.generics.split_for_impl();
+    let allow_deprecated = allow_deprecated(input);
+
+    let module_name = module_name(&cont);
+    let cx = ty::Context::new(&cont.ident, cont.generics);
+    let module = module(&cx, &cont, &module_name);
+    let schema = schema::expression(&cx, &cont);
+    let dependencies = cx.dependencies.take();
+    let module = template(&module, &dependencies);
+    let schema = template(&schema, &dependencies);
+
+    let impl_block = quote! {
+        #[allow(unused_extern_crates, clippy::useless_attribute)]
+        extern crate serde_derive_rescript_rt as _rescript;
+
+        #[automatically_derived]
+        #allow_deprecated
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// Name of the ReScript module holding the type definition.
+            pub const RESCRIPT_MODULE_NAME: &'static str = #module_name;
+            /// ReScript module holding the type definition, i.e. `module Name = { ... }`.
+            pub fn rescript_module() -> ::std::string::String {
+                #module
+            }
+            /// sury schema of the ReScript type, matching the `SerializeDto` output.
+            pub fn rescript_schema() -> ::std::string::String {
+                #schema
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics _rescript::RescriptType for #ident #ty_generics #where_clause {
+            const NAME: &'static str = #module_name;
+
+            fn collect(__collector: &mut _rescript::Collector) {
+                let __module = _rescript::Module {
+                    name: #module_name,
+                    path: ::core::module_path!(),
+                    source: Self::rescript_module(),
+                };
+                if __collector.enter(__module) {
+                    #(<#dependencies as _rescript::RescriptType>::collect(__collector);)*
+                    __collector.leave();
+                }
+            }
+        }
+    };
+
+    Ok(dummy::wrap_in_const(
+        cont.attrs.custom_serde_path(),
+        impl_block,
+    ))
+}
+
+fn precondition(cx: &Ctxt, cont: &Container) {
+    if let Some(name) = cont.attrs.rescript_name() {
+        if !rescript::is_constructor(name) {
+            cx.error_spanned_by(
+                cont.original,
+                format!("`{}` is not a valid ReScript module name", name),
+            );
+        }
+    }
+    match cont.attrs.identifier() {
+        attr::Identifier::No => {}
+        attr::Identifier::Field | attr::Identifier::Variant => {
+            cx.error_spanned_by(cont.original, "identifiers have no ReScript type");
+        }
+    }
+    for field in cont.data.all_fields() {
+        if field.attrs.flatten() {
+            cx.error_spanned_by(
+                field.original,
+                "flattened fields have no ReScript representation",
+            );
+        }
+    }
+    if let Data::Enum(variants) = &cont.data {
+        if let attr::TagType::Adjacent { .. } = cont.attrs.tag() {
+            cx.error_spanned_by(
+                cont.original,
+                "adjacently tagged enums have no ReScript representation",
+            );
+        }
+        if !matches!(cont.attrs.tag(), attr::TagType::None) {
+            for variant in variants {
+                if variant.attrs.untagged() && !variant.attrs.skip_serializing() {
+                    cx.error_spanned_by(
+                        variant.original,
+                        "untagged variants of tagged enums have no ReScript representation",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Expression building `source` at run time, with the module names of the
+/// types it refers to filled in from their `RescriptType::NAME`.
+fn template(source: &str, dependencies: &[syn::Type]) -> TokenStream {
+    let parts = source.split(ty::PLACEHOLDER).enumerate().map(|(i, part)| {
+        if i % 2 == 0 {
+            quote!(#part)
+        } else {
+            let dependency = &dependencies[part.parse::<usize>().unwrap()];
+            quote!(<#dependency as _rescript::RescriptType>::NAME)
+        }
+    });
+    quote!([#(#parts),*].concat())
+}
+
+/// Name of the exported module, `#[rescript(name = "...")]` or the Rust name.
+fn module_name(cont: &Container) -> String {
+    match cont.attrs.rescript_name() {
+        Some(name) => name.to_owned(),
+        None => cont.ident.to_string(),
+    }
+}
+
+/// `module Name = { ... }` source for the container.
+fn module(cx: &ty::Context, cont: &Container, name: &str) -> String {
+    let mut module = format!("module {} = {{\n", name);
+    for line in type_::definition(cx, cont) {
+        for line in line.lines() {
+            module.push_str("  ");
+            module.push_str(line);
+            module.push('\n');
+        }
+    }
+    module.push_str("}\n");
+    module
+}
+
+/// Fields that end up in the serialized output.
+fn serialized<'a>(fields: &'a [Field<'a>]) -> impl Iterator<Item = &'a Field<'a>> {
+    fields
+        .iter()
+        .filter(|field| !field.attrs.skip_serializing())
+}
+
+/// Whether the variant is written without a payload, like `ser.rs` does for
+/// newtype variants whose only field is skipped.
+fn is_unit_variant(variant: &Variant) -> bool {
+    match variant.style {
+        Style::Unit => true,
+        Style::Newtype => variant.fields[0].attrs.skip_serializing(),
+        Style::Struct | Style::Tuple => false,
+    }
+}
+
+/// ReScript record label of a field: its Rust name in camelCase, `_0`, `_1`,
+/// ... for positional members, or the escape recorded by the checks if that
+/// is not a valid label.
+fn field_label(field: &Field) -> String {
+    match field.attrs.rescript_label() {
+        Some(label) => label.to_owned(),
+        None => rescript::field_label(field),
+    }
+}
+
+/// ReScript constructor of a variant: its Rust name, or the escape recorded
+/// by the checks if that is not a valid constructor.
+fn constructor_name(variant: &Variant) -> String {
+    match variant.attrs.rescript_constructor() {
+        Some(constructor) => constructor.to_owned(),
+        None => variant.ident.unraw().to_string(),
+    }
+}
+
+/// ReScript record label of an internal tag: its name in camelCase, escaped
+/// like field labels.
+fn tag_label(tag: &str) -> String {
+    let label = RenameRule::CamelCase.apply_to_field(tag);
+    if rescript::is_label(&label) {
+        label
+    } else {
+        rescript::escape_label(&label)
+    }
+}
+
+/// Polymorphic variant tag: `#Red`, or `#"red-ish"` for names that are not
+/// identifiers or are keywords.
+fn polyvariant(name: &str) -> String {
+    let is_ident = name.starts_with(|ch: char| ch.is_ascii_alphabetic() || ch == '_')
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+        && !rescript::is_keyword(name);
+    if is_ident {
+        format!("#{}", name)
+    } else {
+        format!("#{}", string_literal(name))
+    }
+}
+
+/// ReScript literal of a typed tag value, as used in `@as(...)`.
+fn tag_value_literal(value: attr::TagValue) -> String {
+    match value {
+        attr::TagValue::Bool(value) => value.to_string(),
+        attr::TagValue::Int(value) => value.to_string(),
+        attr::TagValue::Float(value) => rescript::float_json(value),
+        attr::TagValue::Null => "null".to_owned(),
+    }
+}
+
+/// ReScript string literal.
+fn string_literal(value: &str) -> String {
+    let mut literal = String::with_capacity(value.len() + 2);
+    literal.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            ch => literal.push(ch),
+        }
+    }
+    literal.push('"');
+    literal
+}
@@ -0,0 +1,215 @@
+//! Generates the sury schema of a container.
+//!
+//! The schema describes exactly what `SerializeDto` writes, so it can be used
+//! where the `@schema` ppx can't express the wire format, e.g. for string
+//! literals of mixed enums or the `TAG` discriminator of internally tagged
+//! unit variants.
+
+use crate::export::ty::{Context, Type};
+use crate::export::{
+    constructor_name, field_label, is_unit_variant, polyvariant, serialized, string_literal,
+    tag_label, tag_value_literal,
+};
+use crate::internals::ast::{Container, Data, Field, Style, Variant};
+use crate::internals::attr;
+use crate::rescript;
+
+/// Returns the schema expression of `t`. Generic types get a function from
+/// the schemas of their type parameters, recursive types are wrapped in
+/// `S.recursive`.
+pub fn expression(cx: &Context, cont: &Container) -> String {
+    let body = match &cont.data {
+        Data::Enum(variants) => enum_schema(cx, cont, variants),
+        Data::Struct(style, fields) => struct_schema(cx, cont, *style, fields),
+    };
+    let body = if cx.recursive.get() {
+        format!("S.recursive(self => {})", body)
+    } else {
+        body
+    };
+    let params = cx.schema_params();
+    if params.is_empty() {
+        body
+    } else {
+        format!("({}) => {}", params.join(", "), body)
+    }
+}
+
+fn struct_schema(cx: &Context, cont: &Container, style: Style, fields: &[Field]) -> String {
+    if let Some(into) = cont.attrs.type_into() {
+        return Type::from_syn(cx, into).schema();
+    }
+    if cont.attrs.transparent() {
+        if let Some(field) = fields.iter().find(|field| field.attrs.transparent()) {
+            return Type::from_field(cx, field).schema();
+        }
+    }
+    match style {
+        Style::Struct => {
+            let mut record = Vec::new();
+            if let attr::TagType::Internal { tag } = cont.attrs.tag() {
+                let name = string_literal(&cont.attrs.name().serialize_name().value);
+                record.push(format!(
+                    "{}: s.field({}, S.literal({}))",
+                    tag_label(tag),
+                    string_literal(tag),
+                    name,
+                ));
+            }
+            record.extend(record_fields(cx, fields));
+            if record.is_empty() {
+                return "S.dict(S.json)".to_owned();
+            }
+            let mut body = "S.object(s => {\n".to_owned();
+            for field in record {
+                body.push_str(&format!("  {},\n", field));
+            }
+            body.push_str("})");
+            body
+        }
+        Style::Tuple => format!("S.tuple(s => {})", tuple_items(cx, fields)),
+        Style::Newtype => Type::from_field(cx, &fields[0]).schema(),
+        Style::Unit => "S.nullAsUnit".to_owned(),
+    }
+}
+
+fn enum_schema(cx: &Context, cont: &Container, variants: &[Variant]) -> String {
+    let is_mixed_enum = rescript::is_mixed_enum(variants);
+    let variants: Vec<_> = variants
+        .iter()
+        .filter(|variant| !variant.attrs.skip_serializing())
+        .collect();
+    if variants.is_empty() {
+        return "S.never".to_owned();
+    }
+
+    let mut body = "S.union([\n".to_owned();
+    for variant in variants {
+        let collect = variant.attrs.serialize_with().is_none();
+        let schema = cx.collecting(collect, || match cont.attrs.tag() {
+            _ if cont.attrs.polymorphic() => {
+                let name = &variant.attrs.name().serialize_name().value;
+                format!("S.literal({})", polyvariant(name))
+            }
+            attr::TagType::None => untagged_variant(cx, variant),
+            _ => tagged_variant(cx, cont, variant, is_mixed_enum),
+        });
+        for line in schema.lines() {
+            body.push_str("  ");
+            body.push_str(line);
+            body.push('\n');
+        }
+        body.insert(body.len() - 1, ',');
+    }
+    body.push_str("])");
+    body
+}
+
+/// Mirrors `ser::serialize_variant`: unit variants are bare strings (or numbers
+/// with `as_int`) unless the enum is internally tagged without being mixed,
+/// payload variants carry the tag next to their fields.
+fn tagged_variant(
+    cx: &Context,
+    cont: &Container,
+    variant: &Variant,
+    is_mixed_enum: bool,
+) -> String {
+    let ident = constructor_name(variant);
+    let is_bare = match (cont.attrs.tag(), variant.style) {
+        (attr::TagType::External, Style::Unit) => true,
+        (attr::TagType::External, Style::Newtype) => is_mixed_enum && is_unit_variant(variant),
+        (attr::TagType::Internal { .. }, Style::Unit) => is_mixed_enum,
+        _ => false,
+    };
+    if is_bare {
+        return format!("S.literal({})", ident);
+    }
+
+    let tag = string_literal(&rescript::get_effective_tag(&cont.attrs));
+    let name = match variant.attrs.tag_value() {
+        Some(attr::TagValue::Null) => "Null.null".to_owned(),
+        Some(value) => tag_value_literal(value),
+        None => string_literal(&variant.attrs.name().serialize_name().value),
+    };
+    let value = if is_unit_variant(variant) {
+        ident
+    } else {
+        match variant.style {
+            Style::Struct => format!(
+                "{}({{{}}})",
+                ident,
+                record_fields(cx, &variant.fields).join(", ")
+            ),
+            Style::Tuple | Style::Newtype => {
+                let fields: Vec<_> = serialized(&variant.fields)
+                    .map(|field| field_schema(cx, field))
+                    .collect();
+                format!("{}({})", ident, fields.join(", "))
+            }
+            Style::Unit => unreachable!(),
+        }
+    };
+    format!(
+        "S.object(s => {{\n  s.tag({}, {})\n  {}\n}})",
+        tag, name, value
+    )
+}
+
+/// Mirrors `ser::serialize_untagged_variant`: the payload alone, `null` for
+/// unit variants.
+fn untagged_variant(cx: &Context, variant: &Variant) -> String {
+    let ident = constructor_name(variant);
+    if is_unit_variant(variant) {
+        return format!("S.literal({})", ident);
+    }
+    match variant.style {
+        Style::Struct => {
+            let fields = record_fields(cx, &variant.fields);
+            format!("S.object(s => {}({{{}}}))", ident, fields.join(", "))
+        }
+        Style::Tuple => format!(
+            "S.tuple(s => {}({}))",
+            ident,
+            tuple_items(cx, &variant.fields)
+        ),
+        Style::Newtype => format!(
+            "{}->S.shape(v => {}(v))",
+            Type::from_field(cx, &variant.fields[0]).schema(),
+            ident,
+        ),
+        Style::Unit => unreachable!(),
+    }
+}
+
+fn record_fields(cx: &Context, fields: &[Field]) -> Vec<String> {
+    serialized(fields)
+        .map(|field| format!("{}: {}", field_label(field), field_schema(cx, field)))
+        .collect()
+}
+
+/// `s.field(...)` reading a field under its serialized name. Fields with
+/// `skip_serializing_if` may be missing altogether.
+fn field_schema(cx: &Context, field: &Field) -> String {
+    let name = string_literal(&field.attrs.name().serialize_name().value);
+    let ty = Type::from_field(cx, field);
+    match (field.attrs.skip_serializing_if().is_some(), ty.is_option()) {
+        (false, _) => format!("s.field({}, {})", name, ty.schema()),
+        (true, false) => format!("s.field({}, S.option({}))", name, ty.schema()),
+        // `Option<Option<T>>`: missing is `None`, `null` is `Some(None)`
+        (true, true) if rescript::nullable_field_inner(field).is_some() => {
+            let Type::Option(inner) = &ty else {
+                unreachable!()
+            };
+            format!("s.field({}, S.option({}))", name, inner.schema())
+        }
+        (true, true) => format!("s.fieldOr({}, {}, None)", name, ty.schema()),
+    }
+}
+
+fn tuple_items(cx: &Context, fields: &[Field]) -> String {
+    let items: Vec<_> = serialized(fields)
+        .enumerate()
+        .map(|(i, field)| format!("s.item({}, {})", i, Type::from_field(cx, field).schema()))
+        .collect();
+    format!("({})", items.join(", "))
+}
@@ -0,0 +1,371 @@
+//! Mapping of Rust field types to ReScript types and sury schemas.
+//!
+//! The mapping is purely syntactic, so type aliases and re-exports under a
+//! different name are not resolved. Any path that is not recognized is
+//! assumed to be another exported type and refers to its module, i.e.
+//! `Address` -> `Address.t` / `Address.schema`. The module name is left as a
+//! placeholder and filled in from `RescriptType::NAME` of the type, see
+//! `dependency_name`. The wrapper types of the
+//! runtime crate (`Date`, `BigInt`, `Dict`, `Nullable`) and its `with`
+//! modules (`date`, `bigint`, `dict`) are recognized by their full path,
+//! i.e. `serde_derive_rescript_rt::Date`, so that types of the same name
+//! elsewhere are left alone.
+
+use crate::internals::ast::Field;
+use crate::internals::ungroup;
+use quote::ToTokens as _;
+use std::cell::{Cell, RefCell};
+use syn::{GenericArgument, Ident, PathArguments};
+
+/// What the type being exported looks like from the inside.
+pub struct Context<'a> {
+    /// Name of the type being exported. References to it become `t`.
+    pub this: &'a Ident,
+    /// Type parameters of the type being exported. References to them become
+    /// type variables, i.e. `T` -> `'t`.
+    pub type_params: Vec<&'a Ident>,
+    /// Set once the type refers to itself and needs `type rec`.
+    pub recursive: Cell<bool>,
+    /// Other exported types referred to, in order of appearance.
+    pub dependencies: RefCell<Vec<syn::Type>>,
+    /// Cleared while describing types that are not written by their own
+    /// impl, which are not collected.
+    collect: Cell<bool>,
+}
+
+impl<'a> Context<'a> {
+    pub fn new(this: &'a Ident, generics: &'a syn::Generics) -> Self {
+        Context {
+            this,
+            type_params: generics.type_params().map(|param| &param.ident).collect(),
+            recursive: Cell::new(false),
+            dependencies: RefCell::new(Vec::new()),
+            collect: Cell::new(true),
+        }
+    }
+
+    /// Runs `f` without collecting the types it refers to unless `collect`.
+    pub fn collecting<T>(&self, collect: bool, f: impl FnOnce() -> T) -> T {
+        let prev = self.collect.replace(self.collect.get() && collect);
+        let result = f();
+        self.collect.set(prev);
+        result
+    }
+
+    /// Type variables of `t`, i.e. `<'a, 'b>`, or an empty string.
+    pub fn type_vars(&self) -> String {
+        if self.type_params.is_empty() {
+            String::new()
+        } else {
+            let vars: Vec<_> = self
+                .type_params
+                .iter()
+                .map(|param| format!("'{}", var_name(param)))
+                .collect();
+            format!("<{}>", vars.join(", "))
+        }
+    }
+
+    /// Schema arguments of `schema`, i.e. `aSchema, bSchema`.
+    pub fn schema_params(&self) -> Vec<String> {
+        self.type_params
+            .iter()
+            .map(|param| format!("{}Schema", var_name(param)))
+            .collect()
+    }
+}
+
+/// Name of the type variable for a Rust type parameter: `T` -> `t`,
+/// `Key` -> `key`.
+fn var_name(ident: &Ident) -> String {
+    let name = ident.to_string();
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => name,
+    }
+}
+
+/// ReScript counterpart of a Rust type.
+pub enum Type {
+    Bool,
+    Int,
+    Float,
+    String,
+    Unit,
+    Json,
+    /// `Date.t`, written as epoch milliseconds.
+    Date,
+    /// `bigint`, written as a string.
+    BigInt,
+    Option(Box<Type>),
+    /// `Nullable.t`, i.e. `null` or `undefined`, read as an `option`.
+    Nullable(Box<Type>),
+    Array(Box<Type>),
+    Dict(Box<Type>),
+    /// `Result` as written with `#[serde(rescript_result)]`.
+    Result(Box<Type>, Box<Type>),
+    /// `Result` as written by serde: `{"Ok": value}` or `{"Err": error}`,
+    /// read into a ReScript `result`.
+    ExternalResult(Box<Type>, Box<Type>),
+    Tuple(Vec<Type>),
+    /// Type parameter of the exported type.
+    Var(String),
+    /// The exported type itself.
+    This(Vec<Type>),
+    /// Given with `#[rescript(type = "...", schema = "...")]`.
+    Custom(String, String),
+    /// Another exported type, by module name.
+    Module(String, Vec<Type>),
+}
+
+impl Type {
+    /// The type of a field. Fields written by `serialize_with` are described
+    /// by their Rust type, but that type is not collected, since it doesn't
+    /// need to implement `RescriptType`. The `with` modules of the runtime
+    /// crate give the type they write instead.
+    pub fn from_field(cx: &Context, field: &Field) -> Self {
+        if let (Some(rescript), Some(schema)) =
+            (field.attrs.rescript_type(), field.attrs.rescript_schema())
+        {
+            return Type::Custom(rescript.to_owned(), schema.to_owned());
+        }
+        if field.attrs.rescript_result() {
+            return match Type::from_syn(cx, field.ty) {
+                Type::ExternalResult(ok, err) => Type::Result(ok, err),
+                ty => ty,
+            };
+        }
+        let Some(with) = field.attrs.serialize_with() else {
+            return Type::from_syn(cx, field.ty);
+        };
+        match with_module(&with.path).as_deref() {
+            Some("date") => Type::Date,
+            Some("bigint") => Type::BigInt,
+            // `dict` writes the values themselves, which are collected
+            Some("dict") => Type::from_syn(cx, field.ty),
+            _ => cx.collecting(false, || Type::from_syn(cx, field.ty)),
+        }
+    }
+
+    pub fn from_syn(cx: &Context, ty: &syn::Type) -> Self {
+        match ungroup(ty) {
+            syn::Type::Path(ty) if ty.qself.is_none() => {
+                let Some(seg) = ty.path.segments.last() else {
+                    return Type::Json;
+                };
+                let mut args: Vec<_> = type_args(&seg.arguments)
+                    .into_iter()
+                    .map(|arg| Type::from_syn(cx, arg))
+                    .collect();
+                let ident = seg.ident.to_string();
+                if is_runtime_item(&ty.path) {
+                    match ident.as_str() {
+                        "Nullable" => return Type::Nullable(Box::new(nth(args, 0))),
+                        "Dict" => return Type::Dict(Box::new(nth(args, 0))),
+                        "Date" => return Type::Date,
+                        "BigInt" => return Type::BigInt,
+                        _ => {}
+                    }
+                }
+                match ident.as_str() {
+                    _ if ty.path.segments.len() == 1 && cx.type_params.contains(&&seg.ident) => {
+                        Type::Var(var_name(&seg.ident))
+                    }
+                    "Self" => cx.this(cx.type_params.iter().map(|p| Type::Var(var_name(p)))),
+                    _ if seg.ident == *cx.this => cx.this(args),
+                    "bool" => Type::Bool,
+                    "i8" | "i16" | "i32" | "u8" | "u16" | "u32" | "isize" | "usize" => Type::Int,
+                    "i64" | "i128" | "u64" | "u128" | "f32" | "f64" => Type::Float,
+                    "String" | "str" | "char" => Type::String,
+                    "Option" => Type::Option(Box::new(nth(args, 0))),
+                    "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "BinaryHeap" => {
+                        Type::Array(Box::new(nth(args, 0)))
+                    }
+                    "HashMap" | "BTreeMap" => Type::Dict(Box::new(nth(args, 1))),
+                    "Result" if args.len() == 2 => {
+                        let err = args.pop().unwrap();
+                        let ok = args.pop().unwrap();
+                        Type::ExternalResult(Box::new(ok), Box::new(err))
+                    }
+                    "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock" => nth(args, 0),
+                    "Cow" => args.pop().unwrap_or(Type::Json),
+                    "PhantomData" => Type::Unit,
+                    "Value" => Type::Json,
+                    _ => match cx.depend_on(ty) {
+                        Some(index) => Type::Module(dependency_name(index), args),
+                        None => Type::Module(ident, args),
+                    },
+                }
+            }
+            syn::Type::Reference(ty) => Type::from_syn(cx, &ty.elem),
+            syn::Type::Paren(ty) => Type::from_syn(cx, &ty.elem),
+            syn::Type::Slice(ty) => Type::Array(Box::new(Type::from_syn(cx, &ty.elem))),
+            syn::Type::Array(ty) => Type::Array(Box::new(Type::from_syn(cx, &ty.elem))),
+            syn::Type::Tuple(ty) if ty.elems.is_empty() => Type::Unit,
+            syn::Type::Tuple(ty) => Type::Tuple(
+                ty.elems
+                    .iter()
+                    .map(|elem| Type::from_syn(cx, elem))
+                    .collect(),
+            ),
+            _ => Type::Json,
+        }
+    }
+
+    pub fn is_option(&self) -> bool {
+        matches!(self, Type::Option(_) | Type::Nullable(_))
+    }
+
+    /// ReScript type expression, i.e. `option<array<string>>`.
+    pub fn rescript(&self) -> String {
+        match self {
+            Type::Bool => "bool".to_owned(),
+            Type::Int => "int".to_owned(),
+            Type::Float => "float".to_owned(),
+            Type::String => "string".to_owned(),
+            Type::Unit => "unit".to_owned(),
+            Type::Json => "JSON.t".to_owned(),
+            Type::Date => "Date.t".to_owned(),
+            Type::BigInt => "bigint".to_owned(),
+            Type::Option(ty) | Type::Nullable(ty) => format!("option<{}>", ty.rescript()),
+            Type::Array(ty) => format!("array<{}>", ty.rescript()),
+            Type::Dict(ty) => format!("dict<{}>", ty.rescript()),
+            Type::Result(ok, err) | Type::ExternalResult(ok, err) => {
+                format!("result<{}, {}>", ok.rescript(), err.rescript())
+            }
+            Type::Tuple(elems) => format!("({})", join(elems, Type::rescript)),
+            Type::Var(name) => format!("'{}", name),
+            Type::This(args) if args.is_empty() => "t".to_owned(),
+            Type::This(args) => format!("t<{}>", join(args, Type::rescript)),
+            Type::Custom(rescript, _) => rescript.clone(),
+            Type::Module(module, args) if args.is_empty() => format!("{}.t", module),
+            Type::Module(module, args) => format!("{}.t<{}>", module, join(args, Type::rescript)),
+        }
+    }
+
+    /// sury schema expression, i.e. `S.null(S.array(S.string))`.
+    ///
+    /// `Option` maps to `S.null` because `None` is written as `null`. The
+    /// exported type itself is referred to as `self`, which is bound by
+    /// `S.recursive`.
+    pub fn schema(&self) -> String {
+        match self {
+            Type::Bool => "S.bool".to_owned(),
+            Type::Int => "S.int".to_owned(),
+            Type::Float => "S.float".to_owned(),
+            Type::String => "S.string".to_owned(),
+            Type::Unit => "S.nullAsUnit".to_owned(),
+            Type::Json => "S.json".to_owned(),
+            Type::Date => {
+                "S.float->S.transform(_ => {parser: Date.fromTime, serializer: Date.getTime})"
+                    .to_owned()
+            }
+            Type::BigInt => "S.bigint".to_owned(),
+            Type::Option(ty) => format!("S.null({})", ty.schema()),
+            Type::Nullable(ty) => format!("S.nullable({})", ty.schema()),
+            Type::Array(ty) => format!("S.array({})", ty.schema()),
+            Type::Dict(ty) => format!("S.dict({})", ty.schema()),
+            Type::Result(ok, err) => format!(
+                "S.union([S.object(s => {{ s.tag(\"TAG\", \"Ok\"); Ok(s.field(\"_0\", {})) }}), S.object(s => {{ s.tag(\"TAG\", \"Error\"); Error(s.field(\"_0\", {})) }})])",
+                ok.schema(),
+                err.schema(),
+            ),
+            Type::ExternalResult(ok, err) => format!(
+                "S.union([S.object(s => Ok(s.field(\"Ok\", {}))), S.object(s => Error(s.field(\"Err\", {})))])",
+                ok.schema(),
+                err.schema(),
+            ),
+            Type::Tuple(elems) => {
+                let items: Vec<_> = elems
+                    .iter()
+                    .enumerate()
+                    .map(|(i, elem)| format!("s.item({}, {})", i, elem.schema()))
+                    .collect();
+                format!("S.tuple(s => ({}))", items.join(", "))
+            }
+            Type::Var(name) => format!("{}Schema", name),
+            Type::This(_) => "self".to_owned(),
+            Type::Custom(_, schema) => schema.clone(),
+            Type::Module(module, args) if args.is_empty() => format!("{}.schema", module),
+            Type::Module(module, args) => {
+                format!("{}.schema({})", module, join(args, Type::schema))
+            }
+        }
+    }
+}
+
+impl Context<'_> {
+    /// Index of `ty` in `dependencies`, unless it is not collected.
+    fn depend_on(&self, ty: &syn::TypePath) -> Option<usize> {
+        if !self.collect.get() {
+            return None;
+        }
+        let ty = syn::Type::Path(ty.clone());
+        let mut dependencies = self.dependencies.borrow_mut();
+        let tokens = ty.to_token_stream().to_string();
+        match dependencies
+            .iter()
+            .position(|dep| dep.to_token_stream().to_string() == tokens)
+        {
+            Some(index) => Some(index),
+            None => {
+                dependencies.push(ty);
+                Some(dependencies.len() - 1)
+            }
+        }
+    }
+
+    fn this(&self, args: impl IntoIterator<Item = Type>) -> Type {
+        self.recursive.set(true);
+        Type::This(args.into_iter().collect())
+    }
+}
+
+/// Whether `path` is an item of the runtime crate, i.e.
+/// `serde_derive_rescript_rt::Date`.
+fn is_runtime_item(path: &syn::Path) -> bool {
+    path.segments.len() == 2 && path.segments[0].ident == "serde_derive_rescript_rt"
+}
+
+/// Module of the runtime crate a `serialize_with` function comes from, i.e.
+/// `date` for `#[serde(with = "serde_derive_rescript_rt::date")]`.
+fn with_module(path: &syn::Path) -> Option<String> {
+    if path.segments.len() == 3 && path.segments[0].ident == "serde_derive_rescript_rt" {
+        Some(path.segments[1].ident.to_string())
+    } else {
+        None
+    }
+}
+
+/// Delimits the index of a dependency whose module name goes in its place.
+pub const PLACEHOLDER: char = '\0';
+
+/// Stands for the module name of the dependency at `index`, which is only
+/// known once the code is compiled, since the type may be exported under
+/// another name with `#[rescript(name = "...")]`.
+fn dependency_name(index: usize) -> String {
+    format!("{0}{1}{0}", PLACEHOLDER, index)
+}
+
+fn nth(args: Vec<Type>, i: usize) -> Type {
+    args.into_iter().nth(i).unwrap_or(Type::Json)
+}
+
+fn join(types: &[Type], f: fn(&Type) -> String) -> String {
+    types.iter().map(f).collect::<Vec<_>>().join(", ")
+}
+
+fn type_args(arguments: &PathArguments) -> Vec<&syn::Type> {
+    match arguments {
+        PathArguments::AngleBracketed(bracketed) => bracketed
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        PathArguments::None | PathArguments::Parenthesized(_) => Vec::new(),
+    }
+}
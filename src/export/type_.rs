@@ -0,0 +1,176 @@
+//! Generates the ReScript type definition of a container.
+
+use crate::export::ty::{Context, Type};
+use crate::export::{
+    constructor_name, field_label, is_unit_variant, polyvariant, serialized, string_literal,
+    tag_label, tag_value_literal,
+};
+use crate::internals::ast::{Container, Data, Field, Style, Variant};
+use crate::internals::attr;
+use crate::rescript;
+
+/// Returns the `type t = ...` declaration together with its decorators,
+/// one line per element, without the module indentation.
+pub fn definition(cx: &Context, cont: &Container) -> Vec<String> {
+    let mut lines = vec!["@schema".to_owned()];
+    let body = match &cont.data {
+        Data::Enum(variants) => {
+            match cont.attrs.tag() {
+                attr::TagType::None => lines.push("@unboxed".to_owned()),
+                _ => {
+                    let tag = rescript::get_effective_tag(&cont.attrs);
+                    if tag != rescript::DEFAULT_TAG {
+                        lines.push(format!("@tag({})", string_literal(&tag)));
+                    }
+                }
+            }
+            enum_body(cx, cont, variants)
+        }
+        Data::Struct(style, fields) => vec![struct_body(cx, cont, *style, fields)],
+    };
+
+    let rec = if cx.recursive.get() { "rec " } else { "" };
+    let mut body = body.into_iter();
+    let first = body.next().unwrap_or_default();
+    lines.push(
+        format!("type {}t{} = {}", rec, cx.type_vars(), first)
+            .trim_end()
+            .to_owned(),
+    );
+    lines.extend(body);
+    lines
+}
+
+fn struct_body(cx: &Context, cont: &Container, style: Style, fields: &[Field]) -> String {
+    if let Some(into) = cont.attrs.type_into() {
+        return Type::from_syn(cx, into).rescript();
+    }
+    if cont.attrs.transparent() {
+        if let Some(field) = fields.iter().find(|field| field.attrs.transparent()) {
+            return Type::from_field(cx, field).rescript();
+        }
+    }
+    match style {
+        Style::Struct => {
+            let mut record = Vec::new();
+            if let attr::TagType::Internal { tag } = cont.attrs.tag() {
+                record.push(format!("{}: string", label(&tag_label(tag), tag)));
+            }
+            record.extend(record_fields(cx, fields));
+            if record.is_empty() {
+                return "dict<JSON.t>".to_owned();
+            }
+            let mut body = "{\n".to_owned();
+            for field in record {
+                body.push_str(&format!("  {},\n", field));
+            }
+            body.push('}');
+            body
+        }
+        Style::Tuple => tuple(cx, fields),
+        Style::Newtype => Type::from_field(cx, &fields[0]).rescript(),
+        Style::Unit => "unit".to_owned(),
+    }
+}
+
+fn enum_body(cx: &Context, cont: &Container, variants: &[Variant]) -> Vec<String> {
+    if cont.attrs.polymorphic() {
+        let tags: Vec<_> = variants
+            .iter()
+            .filter(|variant| !variant.attrs.skip_serializing())
+            .map(|variant| polyvariant(&variant.attrs.name().serialize_name().value))
+            .collect();
+        return vec![format!("[{}]", tags.join(" | "))];
+    }
+    let untagged = matches!(cont.attrs.tag(), attr::TagType::None);
+    let mut lines = vec![String::new()];
+    for variant in variants {
+        if variant.attrs.skip_serializing() {
+            continue;
+        }
+        let name = constructor(variant);
+        // Variants written by `serialize_with` are described by their Rust
+        // types, which are not collected
+        let collect = variant.attrs.serialize_with().is_none();
+        let line = cx.collecting(collect, || {
+            if is_unit_variant(variant) && untagged {
+                // Unit variants of untagged enums are serialized as `null`.
+                format!("| @as(null) {}", constructor_name(variant))
+            } else if is_unit_variant(variant) {
+                format!("| {}", name)
+            } else {
+                match variant.style {
+                    Style::Struct => {
+                        let fields = record_fields(cx, &variant.fields);
+                        format!("| {}({{{}}})", name, fields.join(", "))
+                    }
+                    Style::Tuple if untagged => {
+                        format!("| {}({})", name, tuple(cx, &variant.fields))
+                    }
+                    Style::Tuple => {
+                        let fields: Vec<_> = serialized(&variant.fields)
+                            .map(|field| Type::from_field(cx, field).rescript())
+                            .collect();
+                        format!("| {}({})", name, fields.join(", "))
+                    }
+                    Style::Newtype => {
+                        let ty = Type::from_field(cx, &variant.fields[0]).rescript();
+                        format!("| {}({})", name, ty)
+                    }
+                    Style::Unit => unreachable!(),
+                }
+            }
+        });
+        lines.push(format!("  {}", line));
+    }
+    lines
+}
+
+/// Constructor of a variant, prefixed with `@as` if it is serialized as a
+/// number, with a typed tag or under a name different from the Rust one.
+fn constructor(variant: &Variant) -> String {
+    let ident = constructor_name(variant);
+    if let Some(value) = variant.attrs.as_int() {
+        return format!("@as({}) {}", value, ident);
+    }
+    if let Some(value) = variant.attrs.tag_value() {
+        return format!("@as({}) {}", tag_value_literal(value), ident);
+    }
+    let name = &variant.attrs.name().serialize_name().value;
+    if *name == ident {
+        ident
+    } else {
+        format!("@as({}) {}", string_literal(name), ident)
+    }
+}
+
+fn record_fields(cx: &Context, fields: &[Field]) -> Vec<String> {
+    serialized(fields)
+        .map(|field| {
+            let name = &field.attrs.name().serialize_name().value;
+            let ty = Type::from_field(cx, field);
+            let ty = if field.attrs.skip_serializing_if().is_some() && !ty.is_option() {
+                format!("option<{}>", ty.rescript())
+            } else {
+                ty.rescript()
+            };
+            format!("{}: {}", label(&field_label(field), name), ty)
+        })
+        .collect()
+}
+
+fn tuple(cx: &Context, fields: &[Field]) -> String {
+    let elems: Vec<_> = serialized(fields)
+        .map(|field| Type::from_field(cx, field).rescript())
+        .collect();
+    format!("({})", elems.join(", "))
+}
+
+/// Record label, with an `@as` for the JSON key when the two differ.
+fn label(label: &str, name: &str) -> String {
+    if label == name {
+        label.to_owned()
+    } else {
+        format!("@as({}) {}", string_literal(name), label)
+    }
+}
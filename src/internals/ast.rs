@@ -72,8 +72,14 @@ impl<'a> Container<'a> {
                 Data::Enum(enum_from_ast(cx, &data.variants, attrs.default(), private))
             }
             syn::Data::Struct(data) => {
-                let (style, fields) =
-                    struct_from_ast(cx, &data.fields, None, attrs.default(), private);
+                let (style, fields) = struct_from_ast(
+                    cx,
+                    &data.fields,
+                    None,
+                    attrs.record(),
+                    attrs.default(),
+                    private,
+                );
                 Data::Struct(style, fields)
             }
             syn::Data::Union(_) => {
@@ -86,19 +92,37 @@ impl<'a> Container<'a> {
             Data::Enum(variants) => {
                 for variant in variants {
                     variant.attrs.rename_by_rules(attrs.rename_all_rules());
+                    // ReScript: positional keys (`_0`, `_1`, ...) of tuple and
+                    // newtype variants are never renamed
+                    let field_rules = match variant.style {
+                        Style::Struct => variant
+                            .attrs
+                            .rename_all_rules()
+                            .or(attrs.rename_all_fields_rules()),
+                        Style::Tuple | Style::Newtype | Style::Unit => attr::RenameAllRules::NONE,
+                    };
                     for field in &mut variant.fields {
-                        field.attrs.rename_by_rules(
-                            variant
-                                .attrs
-                                .rename_all_rules()
-                                .or(attrs.rename_all_fields_rules()),
-                        );
+                        field.attrs.rename_by_rules(field_rules);
+                        if attrs.omit_none() && matches!(variant.style, Style::Struct) {
+                            field.attrs.omit_none(field.ty, private);
+                        }
                     }
                 }
             }
-            Data::Struct(_, fields) => {
+            Data::Struct(style, fields) => {
+                // ReScript: positional keys of `#[serde(record)]` structs are
+                // never renamed
+                let field_rules = if attrs.record() {
+                    attr::RenameAllRules::NONE
+                } else {
+                    attrs.rename_all_rules()
+                };
                 for field in fields {
-                    field.attrs.rename_by_rules(attrs.rename_all_rules());
+                    field.attrs.rename_by_rules(field_rules);
+                    // Elements of tuple structs are positional and can't be left out
+                    if attrs.omit_none() && matches!(style, Style::Struct) {
+                        field.attrs.omit_none(field.ty, private);
+                    }
                 }
             }
         }
@@ -144,6 +168,7 @@ fn enum_from_ast<'a>(
                 cx,
                 &variant.fields,
                 Some(&attrs),
+                true,
                 container_default,
                 private,
             );
@@ -171,26 +196,36 @@ fn enum_from_ast<'a>(
     variants
 }
 
+// ReScript: unnamed fields of variants and of `#[serde(record)]` structs are
+// keyed by position (`_0`, `_1`, ...). Such structs are then handled like any
+// other struct with named fields.
 fn struct_from_ast<'a>(
     cx: &Ctxt,
     fields: &'a syn::Fields,
     attrs: Option<&attr::Variant>,
+    keyed_by_position: bool,
     container_default: &attr::Default,
     private: &Ident,
 ) -> (Style, Vec<Field<'a>>) {
+    let from_ast = |fields| {
+        fields_from_ast(
+            cx,
+            fields,
+            attrs,
+            keyed_by_position,
+            container_default,
+            private,
+        )
+    };
     match fields {
-        syn::Fields::Named(fields) => (
-            Style::Struct,
-            fields_from_ast(cx, &fields.named, attrs, container_default, private),
-        ),
-        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => (
-            Style::Newtype,
-            fields_from_ast(cx, &fields.unnamed, attrs, container_default, private),
-        ),
-        syn::Fields::Unnamed(fields) => (
-            Style::Tuple,
-            fields_from_ast(cx, &fields.unnamed, attrs, container_default, private),
-        ),
+        syn::Fields::Named(fields) => (Style::Struct, from_ast(&fields.named)),
+        syn::Fields::Unnamed(fields) if attrs.is_none() && keyed_by_position => {
+            (Style::Struct, from_ast(&fields.unnamed))
+        }
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            (Style::Newtype, from_ast(&fields.unnamed))
+        }
+        syn::Fields::Unnamed(fields) => (Style::Tuple, from_ast(&fields.unnamed)),
         syn::Fields::Unit => (Style::Unit, Vec::new()),
     }
 }
@@ -199,10 +234,11 @@ fn fields_from_ast<'a>(
     cx: &Ctxt,
     fields: &'a Punctuated<syn::Field, Token![,]>,
     attrs: Option<&attr::Variant>,
+    keyed_by_position: bool,
     container_default: &attr::Default,
     private: &Ident,
 ) -> Vec<Field<'a>> {
-    fields
+    let mut fields: Vec<_> = fields
         .iter()
         .enumerate()
         .map(|(i, field)| Field {
@@ -214,5 +250,18 @@ fn fields_from_ast<'a>(
             ty: &field.ty,
             original: field,
         })
-        .collect()
+        .collect();
+    if keyed_by_position {
+        // Keys count only the fields that are (de)serialized, like the
+        // arguments of the ReScript constructor
+        let (mut ser_index, mut de_index) = (0, 0);
+        for field in &mut fields {
+            if let syn::Member::Unnamed(_) = field.member {
+                field.attrs.key_by_position(ser_index, de_index);
+                ser_index += usize::from(!field.attrs.skip_serializing());
+                de_index += usize::from(!field.attrs.skip_deserializing());
+            }
+        }
+    }
+    fields
 }
@@ -1,6 +1,7 @@
 use crate::internals::name::{MultiName, Name};
 use crate::internals::symbol::*;
-use crate::internals::{ungroup, Ctxt};
+use crate::internals::{config, ungroup, Ctxt};
+use crate::rescript::PayloadKind;
 use proc_macro2::{Spacing, Span, TokenStream, TokenTree};
 use quote::ToTokens;
 use std::collections::BTreeSet;
@@ -141,6 +142,12 @@ pub struct RenameAllRules {
 }
 
 impl RenameAllRules {
+    /// Rules that leave names untouched.
+    pub const NONE: Self = RenameAllRules {
+        serialize: RenameRule::None,
+        deserialize: RenameRule::None,
+    };
+
     /// Returns a new `RenameAllRules` with the individual rules of `self` and
     /// `other_rules` joined by `RenameRules::or`.
     pub fn or(self, other_rules: Self) -> Self {
@@ -162,6 +169,12 @@ pub struct Container {
     ser_bound: Option<Vec<syn::WherePredicate>>,
     de_bound: Option<Vec<syn::WherePredicate>>,
     tag: TagType,
+    unboxed: bool,
+    polymorphic: bool,
+    omit_none: bool,
+    record: bool,
+    dto: bool,
+    rescript_name: Option<String>,
     type_from: Option<syn::Type>,
     type_try_from: Option<syn::Type>,
     type_into: Option<syn::Type>,
@@ -247,6 +260,12 @@ impl Container {
         let mut ser_bound = Attr::none(cx, BOUND);
         let mut de_bound = Attr::none(cx, BOUND);
         let mut untagged = BoolAttr::none(cx, UNTAGGED);
+        let mut unboxed = BoolAttr::none(cx, UNBOXED);
+        let mut polymorphic = BoolAttr::none(cx, POLYMORPHIC);
+        let mut omit_none = BoolAttr::none(cx, OMIT_NONE);
+        let mut record = BoolAttr::none(cx, RECORD);
+        let mut dto = BoolAttr::none(cx, DTO);
+        let mut rescript_name = Attr::none(cx, NAME);
         let mut internal_tag = Attr::none(cx, TAG);
         let mut content = Attr::none(cx, CONTENT);
         let mut type_from = Attr::none(cx, FROM);
@@ -260,11 +279,14 @@ impl Container {
         let mut non_exhaustive = false;
 
         for attr in &item.attrs {
-            if attr.path() != SERDE {
-                non_exhaustive |=
-                    matches!(&attr.meta, syn::Meta::Path(path) if path == NON_EXHAUSTIVE);
-                continue;
-            }
+            let namespace = match attr_namespace(attr) {
+                Some(namespace) => namespace,
+                None => {
+                    non_exhaustive |=
+                        matches!(&attr.meta, syn::Meta::Path(path) if path == NON_EXHAUSTIVE);
+                    continue;
+                }
+            };
 
             if let syn::Meta::List(meta) = &attr.meta {
                 if meta.tokens.is_empty() {
@@ -273,6 +295,9 @@ impl Container {
             }
 
             if let Err(err) = attr.parse_nested_meta(|meta| {
+                if namespace == RESCRIPT {
+                    check_rescript_attr(&meta, "container", RESCRIPT_CONTAINER_ATTRS)?;
+                }
                 if meta.path == RENAME {
                     // #[serde(rename = "foo")]
                     // #[serde(rename(serialize = "foo", deserialize = "bar"))]
@@ -411,6 +436,55 @@ impl Container {
                             cx.syn_error(meta.error(msg));
                         }
                     }
+                } else if meta.path == UNBOXED {
+                    // #[serde(unboxed)]
+                    match item.data {
+                        syn::Data::Enum(_) => {
+                            unboxed.set_true(&meta.path);
+                        }
+                        syn::Data::Struct(_) | syn::Data::Union(_) => {
+                            let msg = format!("#[{}(unboxed)] can only be used on enums", namespace);
+                            cx.syn_error(meta.error(msg));
+                        }
+                    }
+                } else if meta.path == POLYMORPHIC {
+                    // #[serde(polymorphic)]
+                    match item.data {
+                        syn::Data::Enum(_) => {
+                            polymorphic.set_true(&meta.path);
+                        }
+                        syn::Data::Struct(_) | syn::Data::Union(_) => {
+                            let msg =
+                                format!("#[{}(polymorphic)] can only be used on enums", namespace);
+                            cx.syn_error(meta.error(msg));
+                        }
+                    }
+                } else if meta.path == OMIT_NONE {
+                    // #[serde(omit_none)]
+                    omit_none.set_true(&meta.path);
+                } else if meta.path == RECORD {
+                    // #[serde(record)]
+                    match &item.data {
+                        syn::Data::Struct(syn::DataStruct {
+                            fields: syn::Fields::Unnamed(_),
+                            ..
+                        }) => {
+                            record.set_true(&meta.path);
+                        }
+                        _ => {
+                            let msg =
+                                format!("#[{}(record)] can only be used on tuple structs", namespace);
+                            cx.syn_error(meta.error(msg));
+                        }
+                    }
+                } else if namespace == RESCRIPT && meta.path == DTO {
+                    // #[rescript(dto)]
+                    dto.set_true(&meta.path);
+                } else if namespace == RESCRIPT && meta.path == NAME {
+                    // #[rescript(name = "User")]
+                    if let Some(s) = get_lit_str(cx, NAME, &meta)? {
+                        rescript_name.set(&meta.path, s.value());
+                    }
                 } else if meta.path == TAG {
                     // #[serde(tag = "type")]
                     if let Some(s) = get_lit_str(cx, TAG, &meta)? {
@@ -516,9 +590,18 @@ impl Container {
             }
         }
 
-        // ReScript modification: default to camelCase for struct fields
+        // ReScript: unboxed enums are untagged enums whose variants are told
+        // apart by the kind of their payload
+        if unboxed.get() && !untagged.get() {
+            untagged.set_true(unboxed.0.tokens.clone());
+        }
+
+        // ReScript modification: default to camelCase for struct fields, unless
+        // the crate picks another rule. `rename_all = "none"` on the container
+        // keeps the Rust names.
+        let default_field_rule = crate_default_rename_rule(cx, item);
         let default_rename_all_rule = match &item.data {
-            syn::Data::Struct(_) => RenameRule::CamelCase,
+            syn::Data::Struct(_) => default_field_rule,
             syn::Data::Enum(_) => RenameRule::None,
             syn::Data::Union(_) => RenameRule::None,
         };
@@ -535,14 +618,20 @@ impl Container {
             rename_all_fields_rules: RenameAllRules {
                 serialize: rename_all_fields_ser_rule
                     .get()
-                    .unwrap_or(RenameRule::CamelCase),
+                    .unwrap_or(default_field_rule),
                 deserialize: rename_all_fields_de_rule
                     .get()
-                    .unwrap_or(RenameRule::CamelCase),
+                    .unwrap_or(default_field_rule),
             },
             ser_bound: ser_bound.get(),
             de_bound: de_bound.get(),
-            tag: decide_tag(cx, item, untagged, internal_tag, content),
+            tag: decide_tag(cx, untagged, internal_tag, content),
+            unboxed: unboxed.get(),
+            polymorphic: polymorphic.get(),
+            omit_none: omit_none.get(),
+            record: record.get(),
+            dto: dto.get(),
+            rescript_name: rescript_name.get(),
             type_from: type_from.get(),
             type_try_from: type_try_from.get(),
             type_into: type_into.get(),
@@ -591,6 +680,44 @@ impl Container {
         &self.tag
     }
 
+    /// `#[serde(unboxed)]`, a ReScript `@unboxed` variant. Implies
+    /// `TagType::None`.
+    pub fn unboxed(&self) -> bool {
+        self.unboxed
+    }
+
+    /// `#[serde(polymorphic)]`, a unit-only enum mirroring a ReScript
+    /// polymorphic variant. Variants are plain strings.
+    pub fn polymorphic(&self) -> bool {
+        self.polymorphic
+    }
+
+    /// `#[serde(omit_none)]`, `Option` fields are left out when `None` and
+    /// read as `None` when missing, like ReScript's optional record fields.
+    pub fn omit_none(&self) -> bool {
+        self.omit_none
+    }
+
+    /// `#[serde(record)]`, a tuple or newtype struct written as an object
+    /// keyed by position, `{"_0": a, "_1": b}`, instead of an array or the bare
+    /// inner value.
+    pub fn record(&self) -> bool {
+        self.record
+    }
+
+    /// `#[rescript(dto)]`, the ReScript form implements the `SerializeDto` and
+    /// `DeserializeDto` traits of the runtime crate, leaving `Serialize` and
+    /// `Deserialize` to upstream serde.
+    pub fn dto(&self) -> bool {
+        self.dto
+    }
+
+    /// `#[rescript(name = "...")]`, the name of the exported ReScript module
+    /// instead of the Rust name of the type.
+    pub fn rescript_name(&self) -> Option<&str> {
+        self.rescript_name.as_deref()
+    }
+
     pub fn type_from(&self) -> Option<&syn::Type> {
         self.type_from.as_ref()
     }
@@ -630,9 +757,33 @@ impl Container {
     }
 }
 
+/// Default `rename_all` rule for the fields of every container in the crate,
+/// from its Cargo.toml, see `config`.
+fn crate_default_rename_rule(cx: &Ctxt, item: &syn::DeriveInput) -> RenameRule {
+    let rule = match config::load() {
+        Ok(config) => config.and_then(|config| config.rename_all),
+        Err(err) => {
+            cx.error_spanned_by(&item.ident, err);
+            None
+        }
+    };
+    let Some(rule) = rule else {
+        return RenameRule::CamelCase;
+    };
+    match RenameRule::from_str(&rule) {
+        Ok(rule) => rule,
+        Err(err) => {
+            cx.error_spanned_by(
+                &item.ident,
+                format!("package.metadata.serde_derive_rescript: {}", err),
+            );
+            RenameRule::CamelCase
+        }
+    }
+}
+
 fn decide_tag(
     cx: &Ctxt,
-    item: &syn::DeriveInput,
     untagged: BoolAttr,
     internal_tag: Attr<String>,
     content: Attr<String>,
@@ -644,25 +795,9 @@ fn decide_tag(
     ) {
         (None, None, None) => TagType::External,
         (Some(_), None, None) => TagType::None,
-        (None, Some((_, tag)), None) => {
-            // Check that there are no tuple variants.
-            if let syn::Data::Enum(data) = &item.data {
-                for variant in &data.variants {
-                    match &variant.fields {
-                        syn::Fields::Named(_) | syn::Fields::Unit => {}
-                        syn::Fields::Unnamed(fields) => {
-                            if fields.unnamed.len() != 1 {
-                                let msg =
-                                    "#[serde(tag = \"...\")] cannot be used with tuple variants";
-                                cx.error_spanned_by(variant, msg);
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-            TagType::Internal { tag }
-        }
+        // ReScript modification: tuple variants are allowed, their payload is
+        // keyed by position next to the tag
+        (None, Some((_, tag)), None) => TagType::Internal { tag },
         (Some((untagged_tokens, ())), Some((tag_tokens, _)), None) => {
             let msg = "enum cannot be both untagged and internally tagged";
             cx.error_spanned_by(untagged_tokens, msg);
@@ -748,6 +883,24 @@ pub struct Variant {
     deserialize_with: Option<syn::ExprPath>,
     borrow: Option<BorrowAttribute>,
     untagged: bool,
+    as_int: Option<i64>,
+    tag_value: Option<TagValue>,
+    rescript_kind: Option<PayloadKind>,
+    rescript_constructor: Option<String>,
+}
+
+/// Typed value written to the tag field in place of the variant name, like
+/// `@as(true)` on a ReScript variant constructor.
+#[derive(Copy, Clone, PartialEq)]
+pub enum TagValue {
+    /// `#[serde(tag_value = true)]`
+    Bool(bool),
+    /// `#[serde(tag_value = 1)]`
+    Int(i64),
+    /// `#[serde(tag_value = 1.5)]`
+    Float(f64),
+    /// `#[serde(tag_value = null)]`
+    Null,
 }
 
 struct BorrowAttribute {
@@ -771,11 +924,14 @@ impl Variant {
         let mut deserialize_with = Attr::none(cx, DESERIALIZE_WITH);
         let mut borrow = Attr::none(cx, BORROW);
         let mut untagged = BoolAttr::none(cx, UNTAGGED);
+        let mut as_int = Attr::none(cx, AS_INT);
+        let mut tag_value = Attr::none(cx, TAG_VALUE);
+        let mut rescript_kind = Attr::none(cx, KIND);
 
         for attr in &variant.attrs {
-            if attr.path() != SERDE {
+            let Some(namespace) = attr_namespace(attr) else {
                 continue;
-            }
+            };
 
             if let syn::Meta::List(meta) = &attr.meta {
                 if meta.tokens.is_empty() {
@@ -784,6 +940,9 @@ impl Variant {
             }
 
             if let Err(err) = attr.parse_nested_meta(|meta| {
+                if namespace == RESCRIPT {
+                    check_rescript_attr(&meta, "variant", RESCRIPT_VARIANT_ATTRS)?;
+                }
                 if meta.path == RENAME {
                     // #[serde(rename = "foo")]
                     // #[serde(rename(serialize = "foo", deserialize = "bar"))]
@@ -890,6 +1049,39 @@ impl Variant {
                     }
                 } else if meta.path == UNTAGGED {
                     untagged.set_true(&meta.path);
+                } else if meta.path == AS_INT {
+                    // #[serde(as_int = 1)]
+                    if let Some(value) = get_lit_int(cx, AS_INT, &meta)? {
+                        as_int.set(&meta.path, value);
+                    }
+                } else if meta.path == TAG_VALUE {
+                    // #[serde(tag_value = true)]
+                    if let Some(value) = get_tag_value(cx, &meta)? {
+                        tag_value.set(&meta.path, value);
+                    }
+                } else if namespace == RESCRIPT && meta.path == KIND {
+                    // #[rescript(kind = "string")]
+                    if let Some(s) = get_lit_str(cx, KIND, &meta)? {
+                        match (PayloadKind::from_name(&s.value()), &variant.fields) {
+                            (Some(kind), syn::Fields::Unnamed(fields))
+                                if fields.unnamed.len() == 1 =>
+                            {
+                                rescript_kind.set(&meta.path, kind);
+                            }
+                            (Some(_), _) => {
+                                let msg = "#[rescript(kind)] may only be used on newtype variants";
+                                cx.error_spanned_by(variant, msg);
+                            }
+                            (None, _) => {
+                                let msg = format!(
+                                    "unknown payload kind `{}`, expected one of {}",
+                                    s.value(),
+                                    PayloadKind::NAMES,
+                                );
+                                cx.error_spanned_by(s, msg);
+                            }
+                        }
+                    }
                 } else {
                     let path = meta.path.to_token_stream().to_string().replace(' ', "");
                     return Err(
@@ -922,6 +1114,10 @@ impl Variant {
             deserialize_with: deserialize_with.get(),
             borrow: borrow.get(),
             untagged: untagged.get(),
+            as_int: as_int.get(),
+            tag_value: tag_value.get(),
+            rescript_kind: rescript_kind.get(),
+            rescript_constructor: None,
         }
     }
 
@@ -983,6 +1179,34 @@ impl Variant {
     pub fn untagged(&self) -> bool {
         self.untagged
     }
+
+    /// `#[serde(as_int = 1)]`, a unit variant represented by a number, like
+    /// `@as(1)` in ReScript.
+    pub fn as_int(&self) -> Option<i64> {
+        self.as_int
+    }
+
+    /// `#[serde(tag_value = true)]`, a variant whose tag field holds a bool,
+    /// number or null instead of the variant name.
+    pub fn tag_value(&self) -> Option<TagValue> {
+        self.tag_value
+    }
+
+    /// `#[rescript(kind = "string")]`, the payload kind of a newtype variant
+    /// of an unboxed enum, for payload types it can't be told from.
+    pub fn rescript_kind(&self) -> Option<PayloadKind> {
+        self.rescript_kind
+    }
+
+    /// Constructor of the exported ReScript variant when the Rust name can't
+    /// be one, e.g. `Active` for `active`.
+    pub fn rescript_constructor(&self) -> Option<&str> {
+        self.rescript_constructor.as_deref()
+    }
+
+    pub fn set_rescript_constructor(&mut self, constructor: String) {
+        self.rescript_constructor = Some(constructor);
+    }
 }
 
 /// Represents field attribute information
@@ -991,6 +1215,8 @@ pub struct Field {
     skip_serializing: bool,
     skip_deserializing: bool,
     skip_serializing_if: Option<syn::ExprPath>,
+    skip_if_human_readable: bool,
+    omits_none: bool,
     default: Default,
     serialize_with: Option<syn::ExprPath>,
     deserialize_with: Option<syn::ExprPath>,
@@ -1000,6 +1226,10 @@ pub struct Field {
     getter: Option<syn::ExprPath>,
     flatten: bool,
     transparent: bool,
+    rescript_result: bool,
+    rescript_type: Option<String>,
+    rescript_schema: Option<String>,
+    rescript_label: Option<String>,
 }
 
 /// Represents the default to use for a field when deserializing.
@@ -1045,6 +1275,10 @@ impl Field {
         let mut borrowed_lifetimes = Attr::none(cx, BORROW);
         let mut getter = Attr::none(cx, GETTER);
         let mut flatten = BoolAttr::none(cx, FLATTEN);
+        let mut rescript_result = BoolAttr::none(cx, RESCRIPT_RESULT);
+        let mut dto = BoolAttr::none(cx, DTO);
+        let mut rescript_type = Attr::none(cx, TYPE);
+        let mut rescript_schema = Attr::none(cx, SCHEMA);
 
         let ident = match &field.ident {
             Some(ident) => Name::from(&unraw(ident)),
@@ -1072,9 +1306,9 @@ impl Field {
         }
 
         for attr in &field.attrs {
-            if attr.path() != SERDE {
+            let Some(namespace) = attr_namespace(attr) else {
                 continue;
-            }
+            };
 
             if let syn::Meta::List(meta) = &attr.meta {
                 if meta.tokens.is_empty() {
@@ -1083,6 +1317,9 @@ impl Field {
             }
 
             if let Err(err) = attr.parse_nested_meta(|meta| {
+                if namespace == RESCRIPT {
+                    check_rescript_attr(&meta, "field", RESCRIPT_FIELD_ATTRS)?;
+                }
                 if meta.path == RENAME {
                     // #[serde(rename = "foo")]
                     // #[serde(rename(serialize = "foo", deserialize = "bar"))]
@@ -1184,6 +1421,34 @@ impl Field {
                 } else if meta.path == FLATTEN {
                     // #[serde(flatten)]
                     flatten.set_true(&meta.path);
+                } else if meta.path == RESCRIPT_RESULT
+                    || namespace == RESCRIPT && meta.path == RESULT
+                {
+                    // #[serde(rescript_result)]
+                    // #[rescript(result)]
+                    if is_result(&field.ty) {
+                        rescript_result.set_true(&meta.path);
+                    } else {
+                        let msg = format!(
+                            "#[{}({})] may only be used on Result fields",
+                            namespace,
+                            meta.path.to_token_stream(),
+                        );
+                        cx.syn_error(meta.error(msg));
+                    }
+                } else if namespace == RESCRIPT && meta.path == DTO {
+                    // #[rescript(dto)]
+                    dto.set_true(&meta.path);
+                } else if namespace == RESCRIPT && meta.path == TYPE {
+                    // #[rescript(type = "string")]
+                    if let Some(s) = get_lit_str(cx, TYPE, &meta)? {
+                        rescript_type.set(&meta.path, s.value());
+                    }
+                } else if namespace == RESCRIPT && meta.path == SCHEMA {
+                    // #[rescript(schema = "S.string")]
+                    if let Some(s) = get_lit_str(cx, SCHEMA, &meta)? {
+                        rescript_schema.set(&meta.path, s.value());
+                    }
                 } else {
                     let path = meta.path.to_token_stream().to_string().replace(' ', "");
                     return Err(
@@ -1196,6 +1461,63 @@ impl Field {
             }
         }
 
+        // ReScript: the exported type of a field is given as a whole, the
+        // schema can't be derived from a type name
+        match (&rescript_type.value, &rescript_schema.value) {
+            (Some(_), None) => cx.error_spanned_by(
+                field,
+                "#[rescript(type = \"...\")] requires #[rescript(schema = \"...\")]",
+            ),
+            (None, Some(_)) => cx.error_spanned_by(
+                field,
+                "#[rescript(schema = \"...\")] requires #[rescript(type = \"...\")]",
+            ),
+            _ => {}
+        }
+
+        // ReScript: `result<'a, 'b>` is a TAG object, and `#[rescript(dto)]`
+        // fields are written in the ReScript form, by the functions of the
+        // runtime crate
+        if rescript_result.get() && dto.get() {
+            cx.error_spanned_by(
+                field,
+                "#[serde(rescript_result)] cannot be combined with #[rescript(dto)]",
+            );
+        }
+        let runtime_with = if rescript_result.get() {
+            Some(("result", "#[serde(rescript_result)]"))
+        } else if dto.get() {
+            Some(("dto", "#[rescript(dto)]"))
+        } else {
+            None
+        };
+        if let Some((module, attr)) = runtime_with {
+            if serialize_with.value.is_some() || deserialize_with.value.is_some() {
+                cx.error_spanned_by(
+                    field,
+                    format!("{} cannot be combined with #[serde(with = \"...\")]", attr),
+                );
+            }
+            let span = Span::call_site();
+            let helper = |name: &str| {
+                let mut path = syn::Path {
+                    leading_colon: None,
+                    segments: Punctuated::new(),
+                };
+                path.segments.push(Ident::new("_serde", span).into());
+                path.segments.push(private.clone().into());
+                path.segments.push(Ident::new(module, span).into());
+                path.segments.push(Ident::new(name, span).into());
+                syn::ExprPath {
+                    attrs: Vec::new(),
+                    qself: None,
+                    path,
+                }
+            };
+            serialize_with.set_if_none(helper("serialize"));
+            deserialize_with.set_if_none(helper("deserialize"));
+        }
+
         // If skip_deserializing, initialize the field to Default::default() unless a
         // different default is specified by `#[serde(default = "...")]` on
         // ourselves or our container (e.g. the struct we are in).
@@ -1260,6 +1582,8 @@ impl Field {
             skip_serializing: skip_serializing.get(),
             skip_deserializing: skip_deserializing.get(),
             skip_serializing_if: skip_serializing_if.get(),
+            skip_if_human_readable: false,
+            omits_none: false,
             default: default.get().unwrap_or(Default::None),
             serialize_with: serialize_with.get(),
             deserialize_with: deserialize_with.get(),
@@ -1269,6 +1593,10 @@ impl Field {
             getter: getter.get(),
             flatten: flatten.get(),
             transparent: false,
+            rescript_result: rescript_result.get(),
+            rescript_type: rescript_type.get(),
+            rescript_schema: rescript_schema.get(),
+            rescript_label: None,
         }
     }
 
@@ -1280,6 +1608,19 @@ impl Field {
         self.name.deserialize_aliases()
     }
 
+    /// ReScript modification: payloads of tuple and newtype variants are
+    /// keyed by position, i.e. `Foo(a, b)` -> `{"TAG": "Foo", "_0": a, "_1": b}`,
+    /// and so are elements of `#[serde(record)]` tuple structs. Positions
+    /// skip the fields that are not serialized, resp. deserialized.
+    pub fn key_by_position(&mut self, ser_index: usize, de_index: usize) {
+        if !self.name.serialize_renamed {
+            self.name.serialize.value = format!("_{}", ser_index);
+        }
+        if !self.name.deserialize_renamed {
+            self.name.deserialize.value = format!("_{}", de_index);
+        }
+    }
+
     pub fn rename_by_rules(&mut self, rules: RenameAllRules) {
         if !self.name.serialize_renamed {
             self.name.serialize.value = rules.serialize.apply_to_field(&self.name.serialize.value);
@@ -1294,6 +1635,39 @@ impl Field {
             .insert(self.name.deserialize.clone());
     }
 
+    /// ReScript: applies `#[serde(omit_none)]` of the container to an `Option`
+    /// field, as if it had `skip_serializing_if = "Option::is_none"` and
+    /// `default`. Attributes set on the field itself take precedence.
+    ///
+    /// The field is only left out of human-readable formats, since formats
+    /// like bincode read struct fields by position.
+    pub fn omit_none(&mut self, ty: &syn::Type, private: &Ident) {
+        if self.flatten || !is_option(ty, |_| true) {
+            return;
+        }
+        self.omits_none = true;
+        if self.skip_serializing_if.is_none() {
+            let span = Span::call_site();
+            let mut path = syn::Path {
+                leading_colon: None,
+                segments: Punctuated::new(),
+            };
+            path.segments.push(Ident::new("_serde", span).into());
+            path.segments.push(private.clone().into());
+            path.segments.push(Ident::new("Option", span).into());
+            path.segments.push(Ident::new("is_none", span).into());
+            self.skip_serializing_if = Some(syn::ExprPath {
+                attrs: Vec::new(),
+                qself: None,
+                path,
+            });
+            self.skip_if_human_readable = true;
+        }
+        if let Default::None = self.default {
+            self.default = Default::Default;
+        }
+    }
+
     pub fn skip_serializing(&self) -> bool {
         self.skip_serializing
     }
@@ -1306,6 +1680,17 @@ impl Field {
         self.skip_serializing_if.as_ref()
     }
 
+    /// ReScript: whether `skip_serializing_if` only applies when the
+    /// serializer is human-readable, see `omit_none`.
+    pub fn skip_if_human_readable(&self) -> bool {
+        self.skip_if_human_readable
+    }
+
+    /// ReScript: whether `omit_none` of the container applies to the field.
+    pub fn omits_none(&self) -> bool {
+        self.omits_none
+    }
+
     pub fn default(&self) -> &Default {
         &self.default
     }
@@ -1338,6 +1723,23 @@ impl Field {
         self.flatten
     }
 
+    /// `#[serde(rescript_result)]`, a `Result` written like ReScript's
+    /// `result<'a, 'b>`: `{"TAG": "Ok", "_0": value}`.
+    pub fn rescript_result(&self) -> bool {
+        self.rescript_result
+    }
+
+    /// ReScript type of the exported field, `#[rescript(type = "...")]`,
+    /// for types that don't implement `RescriptType`.
+    pub fn rescript_type(&self) -> Option<&str> {
+        self.rescript_type.as_deref()
+    }
+
+    /// sury schema of the exported field, `#[rescript(schema = "...")]`.
+    pub fn rescript_schema(&self) -> Option<&str> {
+        self.rescript_schema.as_deref()
+    }
+
     pub fn transparent(&self) -> bool {
         self.transparent
     }
@@ -1345,6 +1747,46 @@ impl Field {
     pub fn mark_transparent(&mut self) {
         self.transparent = true;
     }
+
+    /// Label of the exported ReScript record field when the Rust name can't
+    /// be one, e.g. `type_` for `type`. The field gets `@as("type")`.
+    pub fn rescript_label(&self) -> Option<&str> {
+        self.rescript_label.as_deref()
+    }
+
+    pub fn set_rescript_label(&mut self, label: String) {
+        self.rescript_label = Some(label);
+    }
+}
+
+// ReScript: options of the fork can be written as `#[rescript(...)]`, which
+// upstream serde derives leave alone, as well as `#[serde(...)]`, except for
+// `dto`, which only makes sense next to upstream derives, and the options of
+// the exported type
+const RESCRIPT_CONTAINER_ATTRS: &[Symbol] = &[UNBOXED, POLYMORPHIC, OMIT_NONE, RECORD, DTO, NAME];
+const RESCRIPT_VARIANT_ATTRS: &[Symbol] = &[AS_INT, TAG_VALUE, KIND];
+const RESCRIPT_FIELD_ATTRS: &[Symbol] = &[RESULT, DTO, TYPE, SCHEMA];
+
+/// `serde` or `rescript` for the attributes parsed by the derives.
+fn attr_namespace(attr: &syn::Attribute) -> Option<Symbol> {
+    [SERDE, RESCRIPT]
+        .into_iter()
+        .find(|namespace| attr.path() == *namespace)
+}
+
+/// Rejects anything but the given keys in a `#[rescript(...)]` attribute.
+fn check_rescript_attr(meta: &ParseNestedMeta, kind: &str, allowed: &[Symbol]) -> syn::Result<()> {
+    if allowed.iter().any(|key| meta.path == *key) {
+        return Ok(());
+    }
+    let path = meta.path.to_token_stream().to_string().replace(' ', "");
+    let expected: Vec<_> = allowed.iter().map(|key| format!("`{}`", key)).collect();
+    Err(meta.error(format_args!(
+        "unknown rescript {} attribute `{}`, expected {}",
+        kind,
+        path,
+        expected.join(", "),
+    )))
 }
 
 type SerAndDe<T> = (Option<T>, Option<T>);
@@ -1463,6 +1905,115 @@ fn get_lit_str2(
     }
 }
 
+fn get_lit_int(cx: &Ctxt, attr_name: Symbol, meta: &ParseNestedMeta) -> syn::Result<Option<i64>> {
+    let expr: syn::Expr = meta.value()?.parse()?;
+    let mut value = &expr;
+    while let syn::Expr::Group(e) = value {
+        value = &e.expr;
+    }
+    let (negative, value) = match value {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, &**expr),
+        value => (false, value),
+    };
+    if let syn::Expr::Lit(syn::ExprLit {
+        lit: syn::Lit::Int(lit),
+        ..
+    }) = value
+    {
+        let digits = if negative {
+            format!("-{}", lit.base10_digits())
+        } else {
+            lit.base10_digits().to_owned()
+        };
+        match digits.parse::<i64>() {
+            Ok(value) => Ok(Some(value)),
+            Err(err) => {
+                cx.error_spanned_by(&expr, err);
+                Ok(None)
+            }
+        }
+    } else {
+        cx.error_spanned_by(
+            &expr,
+            format!(
+                "expected serde {} attribute to be an integer: `{} = 1`",
+                attr_name, attr_name
+            ),
+        );
+        Ok(None)
+    }
+}
+
+fn get_tag_value(cx: &Ctxt, meta: &ParseNestedMeta) -> syn::Result<Option<TagValue>> {
+    let expr: syn::Expr = meta.value()?.parse()?;
+    let mut value = &expr;
+    while let syn::Expr::Group(e) = value {
+        value = &e.expr;
+    }
+    let (negative, value) = match value {
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => (true, &**expr),
+        value => (false, value),
+    };
+    let sign = if negative { "-" } else { "" };
+    let parsed = match value {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Bool(lit),
+            ..
+        }) if !negative => Some(Ok(TagValue::Bool(lit.value))),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => Some(
+            format!("{}{}", sign, lit.base10_digits())
+                .parse()
+                .map(TagValue::Int)
+                .map_err(|err| err.to_string()),
+        ),
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Float(lit),
+            ..
+        }) => Some(
+            format!("{}{}", sign, lit.base10_digits())
+                .parse::<f64>()
+                .map_err(|err| err.to_string())
+                .and_then(|value| {
+                    // `1e400` parses as infinity, which has no JSON literal
+                    if value.is_finite() {
+                        Ok(TagValue::Float(value))
+                    } else {
+                        Err(format!("tag_value `{}{}` is out of range", sign, lit))
+                    }
+                }),
+        ),
+        syn::Expr::Path(syn::ExprPath { path, .. }) if !negative && path.is_ident("null") => {
+            Some(Ok(TagValue::Null))
+        }
+        _ => None,
+    };
+    match parsed {
+        Some(Ok(value)) => Ok(Some(value)),
+        Some(Err(err)) => {
+            cx.error_spanned_by(&expr, err);
+            Ok(None)
+        }
+        None => {
+            cx.error_spanned_by(
+                &expr,
+                "expected serde tag_value attribute to be a bool, a number or `null`, use `rename` for string tags",
+            );
+            Ok(None)
+        }
+    }
+}
+
 fn parse_lit_into_path(
     cx: &Ctxt,
     attr_name: Symbol,
@@ -1641,6 +2192,25 @@ fn is_cow(ty: &syn::Type, elem: fn(&syn::Type) -> bool) -> bool {
         }
 }
 
+fn is_result(ty: &syn::Type) -> bool {
+    let path = match ungroup(ty) {
+        syn::Type::Path(ty) => &ty.path,
+        _ => {
+            return false;
+        }
+    };
+    let Some(seg) = path.segments.last() else {
+        return false;
+    };
+    let args = match &seg.arguments {
+        syn::PathArguments::AngleBracketed(bracketed) => &bracketed.args,
+        _ => {
+            return false;
+        }
+    };
+    seg.ident == "Result" && args.len() == 2
+}
+
 fn is_option(ty: &syn::Type, elem: fn(&syn::Type) -> bool) -> bool {
     let path = match ungroup(ty) {
         syn::Type::Path(ty) => &ty.path,
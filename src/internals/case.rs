@@ -31,6 +31,8 @@ pub enum RenameRule {
 }
 
 static RENAME_RULES: &[(&str, RenameRule)] = &[
+    // ReScript: keeps the Rust names, overriding the camelCase default
+    ("none", None),
     ("lowercase", LowerCase),
     ("UPPERCASE", UpperCase),
     ("PascalCase", PascalCase),
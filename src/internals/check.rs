@@ -1,6 +1,8 @@
-use crate::internals::ast::{Container, Data, Field, Style};
-use crate::internals::attr::{Default, Identifier, TagType};
+use crate::internals::ast::{Container, Data, Field, Style, Variant};
+use crate::internals::attr::{Default, Identifier, TagType, TagValue};
 use crate::internals::{ungroup, Ctxt, Derive};
+use crate::rescript::{self, PayloadKind};
+use syn::ext::IdentExt as _;
 use syn::{Member, Type};
 
 // Cross-cutting checks that require looking at more than a single attrs object.
@@ -8,14 +10,20 @@ use syn::{Member, Type};
 pub fn check(cx: &Ctxt, cont: &mut Container, derive: Derive) {
     check_default_on_tuple(cx, cont);
     check_remote_generic(cx, cont);
+    check_dto(cx, cont);
     check_getter(cx, cont);
     check_flatten(cx, cont);
     check_identifier(cx, cont);
     check_variant_skip_attrs(cx, cont);
     check_internal_tag_field_name_conflict(cx, cont);
     check_adjacent_tag_conflict(cx, cont);
+    check_unboxed(cx, cont, derive);
+    check_polymorphic(cx, cont);
+    check_as_int(cx, cont);
+    check_tag_value(cx, cont);
     check_transparent(cx, cont, derive);
     check_from_and_try_from(cx, cont);
+    check_rescript_names(cx, cont, derive);
 }
 
 // If some field of a tuple struct is marked #[serde(default)] then all fields
@@ -73,6 +81,17 @@ fn check_remote_generic(cx: &Ctxt, cont: &Container) {
     }
 }
 
+// `#[rescript(dto)]` implements the traits of the runtime crate for the type
+// itself, while `remote` generates inherent functions for a foreign type.
+fn check_dto(cx: &Ctxt, cont: &Container) {
+    if cont.attrs.dto() && cont.attrs.remote().is_some() {
+        cx.error_spanned_by(
+            cont.original,
+            "#[rescript(dto)] cannot be used with #[serde(remote = \"...\")]",
+        );
+    }
+}
+
 // Getters are only allowed inside structs (not enums) with the `remote`
 // attribute.
 fn check_getter(cx: &Ctxt, cont: &Container) {
@@ -303,21 +322,26 @@ fn check_internal_tag_field_name_conflict(cx: &Ctxt, cont: &Container) {
         Data::Struct(_, _) => return,
     };
 
+    // ReScript: externally tagged enums write their struct variants with the
+    // implicit `TAG` field, so the same conflict applies to them
     let tag = match cont.attrs.tag() {
         TagType::Internal { tag } => tag.as_str(),
+        TagType::External if !cont.attrs.polymorphic() => rescript::DEFAULT_TAG,
         TagType::External | TagType::Adjacent { .. } | TagType::None => return,
     };
 
-    let diagnose_conflict = || {
+    let diagnose_conflict = |field: &Field| {
         cx.error_spanned_by(
-            cont.original,
+            field.original,
             format!("variant field name `{}` conflicts with internal tag", tag),
         );
     };
 
     for variant in variants {
         match variant.style {
-            Style::Struct => {
+            // ReScript: tuple and newtype payloads are written next to the tag
+            // as well, keyed by position
+            Style::Struct | Style::Tuple | Style::Newtype => {
                 if variant.attrs.untagged() {
                     continue;
                 }
@@ -330,19 +354,22 @@ fn check_internal_tag_field_name_conflict(cx: &Ctxt, cont: &Container) {
                     let ser_name = name.serialize_name();
 
                     if check_ser && ser_name.value == tag {
-                        diagnose_conflict();
-                        return;
+                        diagnose_conflict(field);
+                        continue;
                     }
 
-                    for de_name in field.attrs.aliases() {
-                        if check_de && de_name.value == tag {
-                            diagnose_conflict();
-                            return;
-                        }
+                    if check_de
+                        && field
+                            .attrs
+                            .aliases()
+                            .iter()
+                            .any(|de_name| de_name.value == tag)
+                    {
+                        diagnose_conflict(field);
                     }
                 }
             }
-            Style::Unit | Style::Newtype | Style::Tuple => {}
+            Style::Unit => {}
         }
     }
 }
@@ -366,6 +393,189 @@ fn check_adjacent_tag_conflict(cx: &Ctxt, cont: &Container) {
     }
 }
 
+// ReScript: variants of an unboxed enum are told apart by the runtime kind of
+// their payload, so every kind can be used by one variant at most.
+fn check_unboxed(cx: &Ctxt, cont: &Container, derive: Derive) {
+    if !cont.attrs.unboxed() {
+        return;
+    }
+    let Data::Enum(variants) = &cont.data else {
+        return;
+    };
+
+    let mut seen: Vec<(PayloadKind, &syn::Ident)> = Vec::new();
+    for variant in variants {
+        let skipped = match derive {
+            Derive::Serialize | Derive::RescriptType => variant.attrs.skip_serializing(),
+            Derive::Deserialize => variant.attrs.skip_deserializing(),
+        };
+        if skipped {
+            continue;
+        }
+        let Some(kind) = rescript::payload_kind(variant) else {
+            let hint = match variant.style {
+                Style::Newtype => ", declare it with #[rescript(kind = \"...\")]",
+                _ => "",
+            };
+            cx.error_spanned_by(
+                variant.original,
+                format!(
+                    "cannot tell the payload kind of variant `{}` of an unboxed enum{}",
+                    variant.ident, hint
+                ),
+            );
+            continue;
+        };
+        match seen.iter().find(|(seen_kind, _)| *seen_kind == kind) {
+            Some((_, other)) => cx.error_spanned_by(
+                variant.original,
+                format!(
+                    "variants `{}` and `{}` of an unboxed enum both have a {} payload",
+                    other, variant.ident, kind
+                ),
+            ),
+            None => seen.push((kind, &variant.ident)),
+        }
+    }
+}
+
+// ReScript: polymorphic variants are bare strings, so only untagged unit
+// variants can be represented.
+fn check_polymorphic(cx: &Ctxt, cont: &Container) {
+    if !cont.attrs.polymorphic() {
+        return;
+    }
+    let Data::Enum(variants) = &cont.data else {
+        return;
+    };
+
+    if !matches!(cont.attrs.tag(), TagType::External) {
+        cx.error_spanned_by(
+            cont.original,
+            "#[serde(polymorphic)] cannot be used with #[serde(tag = \"...\")], #[serde(untagged)] or #[serde(unboxed)]",
+        );
+    }
+    for variant in variants {
+        if !matches!(variant.style, Style::Unit) {
+            cx.error_spanned_by(
+                variant.original,
+                "#[serde(polymorphic)] may only contain unit variants",
+            );
+        } else if variant.attrs.untagged() {
+            cx.error_spanned_by(
+                variant.original,
+                "#[serde(untagged)] variants cannot appear in a #[serde(polymorphic)] enum",
+            );
+        }
+    }
+}
+
+// ReScript: numeric representations are only available to unit variants that
+// are written as bare values, and must be unique.
+fn check_as_int(cx: &Ctxt, cont: &Container) {
+    let Data::Enum(variants) = &cont.data else {
+        return;
+    };
+
+    let bare_units = match cont.attrs.tag() {
+        TagType::External => !cont.attrs.polymorphic(),
+        TagType::Internal { .. } => rescript::is_mixed_enum(variants),
+        TagType::Adjacent { .. } | TagType::None => false,
+    };
+    let mut seen: Vec<(i64, &syn::Ident)> = Vec::new();
+    for variant in variants {
+        let Some(value) = variant.attrs.as_int() else {
+            continue;
+        };
+        if !matches!(variant.style, Style::Unit) {
+            cx.error_spanned_by(
+                variant.original,
+                "#[serde(as_int)] may only be used on unit variants",
+            );
+        } else if !bare_units {
+            cx.error_spanned_by(
+                variant.original,
+                "#[serde(as_int)] may only be used in externally tagged or mixed enums",
+            );
+        }
+        match seen.iter().find(|(seen_value, _)| *seen_value == value) {
+            Some((_, other)) => cx.error_spanned_by(
+                variant.original,
+                format!(
+                    "variants `{}` and `{}` have the same #[serde(as_int = {})]",
+                    other, variant.ident, value
+                ),
+            ),
+            None => seen.push((value, &variant.ident)),
+        }
+    }
+}
+
+// `tag_value` replaces the variant name in the tag field, so it is only
+// meaningful for variants written as objects with a tag.
+fn check_tag_value(cx: &Ctxt, cont: &Container) {
+    let Data::Enum(variants) = &cont.data else {
+        return;
+    };
+
+    let is_mixed = rescript::is_mixed_enum(variants);
+    let mut seen: Vec<(TagValue, &syn::Ident)> = Vec::new();
+    for variant in variants {
+        let Some(value) = variant.attrs.tag_value() else {
+            continue;
+        };
+        let is_bare_unit = match variant.style {
+            Style::Unit => true,
+            Style::Newtype => variant.fields[0].attrs.skip_serializing(),
+            Style::Struct | Style::Tuple => false,
+        };
+        let is_tagged = !variant.attrs.untagged()
+            && match cont.attrs.tag() {
+                TagType::External => {
+                    !cont.attrs.polymorphic()
+                        && rescript::has_payload(variant)
+                        && !(is_mixed && is_bare_unit)
+                }
+                TagType::Internal { .. } => !is_mixed || rescript::has_payload(variant),
+                TagType::Adjacent { .. } | TagType::None => false,
+            };
+        if !is_tagged {
+            cx.error_spanned_by(
+                variant.original,
+                "#[serde(tag_value)] may only be used on variants written as objects with a tag",
+            );
+        }
+        if variant.attrs.serialize_with().is_some() || variant.attrs.deserialize_with().is_some() {
+            cx.error_spanned_by(
+                variant.original,
+                "#[serde(tag_value)] cannot be combined with #[serde(with = \"...\")] on a variant",
+            );
+        }
+        match seen
+            .iter()
+            .find(|(seen_value, _)| same_tag_value(*seen_value, value))
+        {
+            Some((_, other)) => cx.error_spanned_by(
+                variant.original,
+                format!(
+                    "variants `{}` and `{}` have the same #[serde(tag_value)]",
+                    other, variant.ident
+                ),
+            ),
+            None => seen.push((value, &variant.ident)),
+        }
+    }
+}
+
+// `1` and `1.0` are the same number in JavaScript.
+fn same_tag_value(a: TagValue, b: TagValue) -> bool {
+    match (a, b) {
+        (TagValue::Int(int), TagValue::Float(float))
+        | (TagValue::Float(float), TagValue::Int(int)) => rescript::integral(float) == Some(int),
+        _ => a == b,
+    }
+}
+
 // Enums and unit structs cannot be transparent.
 fn check_transparent(cx: &Ctxt, cont: &mut Container, derive: Derive) {
     if !cont.attrs.transparent() {
@@ -393,6 +603,13 @@ fn check_transparent(cx: &Ctxt, cont: &mut Container, derive: Derive) {
         );
     }
 
+    if cont.attrs.record() {
+        cx.error_spanned_by(
+            cont.original,
+            "#[serde(transparent)] is not allowed with #[serde(record)]",
+        );
+    }
+
     let fields = match &mut cont.data {
         Data::Enum(_) => {
             cx.error_spanned_by(
@@ -429,7 +646,7 @@ fn check_transparent(cx: &Ctxt, cont: &mut Container, derive: Derive) {
     match transparent_field {
         Some(transparent_field) => transparent_field.attrs.mark_transparent(),
         None => match derive {
-            Derive::Serialize => {
+            Derive::Serialize | Derive::RescriptType => {
                 cx.error_spanned_by(
                     cont.original,
                     "#[serde(transparent)] requires at least one field that is not skipped",
@@ -462,7 +679,7 @@ fn allow_transparent(field: &Field, derive: Derive) -> bool {
     }
 
     match derive {
-        Derive::Serialize => !field.attrs.skip_serializing(),
+        Derive::Serialize | Derive::RescriptType => !field.attrs.skip_serializing(),
         Derive::Deserialize => !field.attrs.skip_deserializing() && field.attrs.default().is_none(),
     }
 }
@@ -475,3 +692,231 @@ fn check_from_and_try_from(cx: &Ctxt, cont: &mut Container) {
         );
     }
 }
+
+// ReScript: record labels and variant constructors of the exported type must
+// be valid ReScript identifiers. Labels come from the field names, so a field
+// serialized as a keyword like `type`, or with characters ReScript doesn't
+// allow, gets an escaped label that is written with `@as("type") type_`.
+fn check_rescript_names(cx: &Ctxt, cont: &mut Container, derive: Derive) {
+    if !matches!(derive, Derive::RescriptType) {
+        return;
+    }
+    match &mut cont.data {
+        Data::Enum(variants) => {
+            if !cont.attrs.polymorphic() {
+                escape_constructors(cx, variants);
+            }
+            for variant in variants {
+                if let Style::Struct = variant.style {
+                    escape_labels(cx, &mut variant.fields);
+                }
+            }
+        }
+        Data::Struct(Style::Struct, fields) => escape_labels(cx, fields),
+        Data::Struct(_, _) => {}
+    }
+}
+
+fn escape_labels(cx: &Ctxt, fields: &mut [Field]) {
+    let mut seen: Vec<(String, &Member)> = Vec::new();
+    for field in fields {
+        if field.attrs.skip_serializing() {
+            continue;
+        }
+        let mut label = rescript::field_label(field);
+        if !rescript::is_label(&label) {
+            label = rescript::escape_label(&label);
+            field.attrs.set_rescript_label(label.clone());
+        }
+        match seen.iter().find(|(seen_label, _)| *seen_label == label) {
+            Some((_, other)) => cx.error_spanned_by(
+                field.original,
+                format!(
+                    "fields {} and {} both have the ReScript label `{}`",
+                    member_message(other),
+                    member_message(&field.member),
+                    label
+                ),
+            ),
+            None => seen.push((label, &field.member)),
+        }
+    }
+}
+
+fn escape_constructors(cx: &Ctxt, variants: &mut [Variant]) {
+    let mut seen: Vec<(String, &syn::Ident)> = Vec::new();
+    for variant in variants {
+        if variant.attrs.skip_serializing() {
+            continue;
+        }
+        let mut constructor = variant.ident.unraw().to_string();
+        if !rescript::is_constructor(&constructor) {
+            constructor = rescript::escape_constructor(&constructor);
+            variant.attrs.set_rescript_constructor(constructor.clone());
+        }
+        match seen
+            .iter()
+            .find(|(seen_constructor, _)| *seen_constructor == constructor)
+        {
+            Some((_, other)) => cx.error_spanned_by(
+                variant.original,
+                format!(
+                    "variants `{}` and `{}` both have the ReScript constructor `{}`",
+                    other, variant.ident, constructor
+                ),
+            ),
+            None => seen.push((constructor, &variant.ident)),
+        }
+    }
+}
+
+#[cfg(test)]
+fn errors(input: syn::DeriveInput) -> Vec<String> {
+    let cx = Ctxt::new();
+    let private = syn::Ident::new("__private", proc_macro2::Span::call_site());
+    Container::from_ast(&cx, &input, Derive::Serialize, &private);
+    match cx.check() {
+        Ok(()) => Vec::new(),
+        Err(err) => err.into_iter().map(|err| err.to_string()).collect(),
+    }
+}
+
+#[test]
+fn implicit_tag_conflict() {
+    let input = syn::parse_quote! {
+        enum Shape {
+            Circle {
+                #[serde(rename = "TAG")]
+                kind: u8,
+            },
+            Square { side: u8 },
+        }
+    };
+    assert_eq!(
+        errors(input),
+        ["variant field name `TAG` conflicts with internal tag"],
+    );
+
+    let input = syn::parse_quote! {
+        enum Shape {
+            Empty,
+            Circle {
+                #[serde(alias = "TAG")]
+                kind: u8,
+            },
+        }
+    };
+    assert_eq!(
+        errors(input),
+        ["variant field name `TAG` conflicts with internal tag"],
+    );
+}
+
+#[test]
+fn implicit_tag_conflict_positional() {
+    let input = syn::parse_quote! {
+        enum Shape {
+            Circle(#[serde(rename = "TAG")] u8),
+            Square(u8, #[serde(alias = "TAG")] u8),
+        }
+    };
+    assert_eq!(
+        errors(input),
+        [
+            "variant field name `TAG` conflicts with internal tag",
+            "variant field name `TAG` conflicts with internal tag",
+        ],
+    );
+
+    let input = syn::parse_quote! {
+        #[serde(tag = "kind")]
+        enum Shape {
+            Circle(#[serde(rename = "kind")] u8),
+        }
+    };
+    assert_eq!(
+        errors(input),
+        ["variant field name `kind` conflicts with internal tag"],
+    );
+}
+
+#[test]
+fn tag_value_out_of_range() {
+    let input = syn::parse_quote! {
+        enum Level {
+            #[serde(tag_value = 1e400)]
+            High { value: u8 },
+            #[serde(tag_value = -1e400)]
+            Low { value: u8 },
+        }
+    };
+    assert_eq!(
+        errors(input),
+        [
+            "tag_value `1e400` is out of range",
+            "tag_value `-1e400` is out of range",
+        ],
+    );
+}
+
+#[test]
+fn implicit_tag_conflict_skipped() {
+    let input = syn::parse_quote! {
+        enum Shape {
+            #[serde(skip)]
+            Circle {
+                #[serde(rename = "TAG")]
+                kind: u8,
+            },
+            Square {
+                #[serde(rename = "TAG", skip)]
+                kind: u8,
+            },
+        }
+    };
+    assert!(errors(input).is_empty());
+}
+
+#[test]
+fn unboxed_payload_kind() {
+    let input = syn::parse_quote! {
+        #[serde(unboxed)]
+        enum Value {
+            Text(String),
+            Point(Point),
+        }
+    };
+    assert_eq!(
+        errors(input),
+        ["cannot tell the payload kind of variant `Point` of an unboxed enum, declare it with #[rescript(kind = \"...\")]"],
+    );
+
+    let input = syn::parse_quote! {
+        #[serde(unboxed)]
+        enum Value {
+            #[rescript(kind = "string")]
+            Id(Id),
+            #[rescript(kind = "object")]
+            Point(Point),
+        }
+    };
+    assert!(errors(input).is_empty());
+
+    let input = syn::parse_quote! {
+        #[serde(unboxed)]
+        enum Value {
+            #[rescript(kind = "text")]
+            Id(Id),
+            #[rescript(kind = "array")]
+            Pair(u8, u8),
+        }
+    };
+    assert_eq!(
+        errors(input),
+        [
+            "unknown payload kind `text`, expected one of null, boolean, number, string, array, object",
+            "#[rescript(kind)] may only be used on newtype variants",
+            "cannot tell the payload kind of variant `Id` of an unboxed enum, declare it with #[rescript(kind = \"...\")]",
+        ],
+    );
+}
@@ -0,0 +1,161 @@
+//! Crate-wide options, read from the `[package.metadata.serde_derive_rescript]`
+//! table of the Cargo.toml of the crate being compiled:
+//!
+//! ```toml
+//! [package.metadata.serde_derive_rescript]
+//! rename_all = "snake_case"
+//! ```
+//!
+//! Any other key in the table is an error, so that a typo can't quietly
+//! change the wire format.
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use toml::Value;
+
+const TABLE: &str = "serde_derive_rescript";
+
+/// Options of the crate being compiled.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Cargo.toml the options come from.
+    pub manifest: PathBuf,
+    /// `rename_all`, the default rule for the fields of every container.
+    pub rename_all: Option<String>,
+}
+
+type Loaded = Result<Option<Config>, String>;
+
+thread_local! {
+    // Keyed by modification time as well, since a proc macro server like
+    // rust-analyzer's outlives edits to the manifest
+    static CACHE: RefCell<HashMap<(PathBuf, Option<SystemTime>), Loaded>> =
+        RefCell::new(HashMap::new());
+}
+
+/// Options of the crate being compiled, or `None` if its Cargo.toml has no
+/// `[package.metadata.serde_derive_rescript]` table or the macro doesn't run
+/// under cargo.
+pub fn load() -> Loaded {
+    let Some(dir) = std::env::var_os("CARGO_MANIFEST_DIR") else {
+        return Ok(None);
+    };
+    let manifest = PathBuf::from(dir).join("Cargo.toml");
+    let Ok(modified) = fs::metadata(&manifest).map(|metadata| metadata.modified().ok()) else {
+        return Ok(None);
+    };
+    CACHE.with(|cache| {
+        cache
+            .borrow_mut()
+            .entry((manifest.clone(), modified))
+            .or_insert_with(|| {
+                let source = fs::read_to_string(&manifest)
+                    .map_err(|err| format!("failed to read {}: {}", manifest.display(), err))?;
+                parse(&source, manifest)
+            })
+            .clone()
+    })
+}
+
+fn parse(source: &str, manifest: PathBuf) -> Loaded {
+    let root: Value = source
+        .parse()
+        .map_err(|err| format!("failed to parse {}: {}", manifest.display(), err))?;
+    let table = match root
+        .get("package")
+        .and_then(|package| package.get("metadata"))
+        .and_then(|metadata| metadata.get(TABLE))
+    {
+        None => return Ok(None),
+        Some(Value::Table(table)) => table,
+        Some(_) => return Err(format!("package.metadata.{} must be a table", TABLE)),
+    };
+
+    let mut config = Config {
+        manifest,
+        rename_all: None,
+    };
+    for (key, value) in table {
+        match (key.as_str(), value) {
+            ("rename_all", Value::String(rule)) => config.rename_all = Some(rule.clone()),
+            ("rename_all", _) => {
+                return Err(format!(
+                    "package.metadata.{}.rename_all must be a string",
+                    TABLE
+                ));
+            }
+            _ => {
+                return Err(format!(
+                    "unknown option `{}` in package.metadata.{}",
+                    key, TABLE
+                ));
+            }
+        }
+    }
+    Ok(Some(config))
+}
+
+#[cfg(test)]
+fn rename_all(source: &str) -> Result<Option<String>, String> {
+    parse(source, PathBuf::from("Cargo.toml")).map(|config| config.and_then(|c| c.rename_all))
+}
+
+#[test]
+fn parse_table() {
+    let manifest = r#"
+[package]
+name = "app"
+
+[package.metadata.serde_derive_rescript]
+# fields keep their Rust names
+rename_all = 'none'
+"#;
+    assert_eq!(rename_all(manifest), Ok(Some("none".to_owned())));
+
+    let manifest = r#"
+[package]
+name = "app"
+metadata.serde_derive_rescript = { rename_all = "snake_case" }
+"#;
+    assert_eq!(rename_all(manifest), Ok(Some("snake_case".to_owned())));
+
+    let manifest = r#"
+[package.metadata]
+serde_derive_rescript.rename_all = "kebab-case"
+"#;
+    assert_eq!(rename_all(manifest), Ok(Some("kebab-case".to_owned())));
+}
+
+#[test]
+fn parse_missing_table() {
+    let manifest = r#"
+[package]
+name = "app"
+rename_all = "snake_case"
+"#;
+    assert_eq!(rename_all(manifest), Ok(None));
+}
+
+#[test]
+fn parse_errors() {
+    let manifest = r#"
+[package.metadata.serde_derive_rescript]
+rename-all = "snake_case"
+"#;
+    assert_eq!(
+        rename_all(manifest),
+        Err("unknown option `rename-all` in package.metadata.serde_derive_rescript".to_owned()),
+    );
+
+    let manifest = r#"
+[package.metadata.serde_derive_rescript]
+rename_all = false
+"#;
+    assert_eq!(
+        rename_all(manifest),
+        Err("package.metadata.serde_derive_rescript.rename_all must be a string".to_owned()),
+    );
+}
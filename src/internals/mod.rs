@@ -1,5 +1,6 @@
 pub mod ast;
 pub mod attr;
+pub mod config;
 pub mod name;
 
 mod case;
@@ -11,6 +12,7 @@ mod symbol;
 
 use syn::Type;
 
+pub use self::case::RenameRule;
 pub use self::ctxt::Ctxt;
 pub use self::receiver::replace_receiver;
 
@@ -18,6 +20,9 @@ pub use self::receiver::replace_receiver;
 pub enum Derive {
     Serialize,
     Deserialize,
+    // ReScript: reads the container like `Serialize`, and also checks the
+    // names that end up in the exported ReScript type
+    RescriptType,
 }
 
 pub fn ungroup(mut ty: &Type) -> &Type {
@@ -1,10 +1,11 @@
 use std::fmt::{self, Display};
 use syn::{Ident, Path};
 
-#[derive(Copy, Clone)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Symbol(&'static str);
 
 pub const ALIAS: Symbol = Symbol("alias");
+pub const AS_INT: Symbol = Symbol("as_int");
 pub const BORROW: Symbol = Symbol("borrow");
 pub const BOUND: Symbol = Symbol("bound");
 pub const CONTENT: Symbol = Symbol("content");
@@ -13,19 +14,29 @@ pub const DEFAULT: Symbol = Symbol("default");
 pub const DENY_UNKNOWN_FIELDS: Symbol = Symbol("deny_unknown_fields");
 pub const DESERIALIZE: Symbol = Symbol("deserialize");
 pub const DESERIALIZE_WITH: Symbol = Symbol("deserialize_with");
+pub const DTO: Symbol = Symbol("dto");
 pub const EXPECTING: Symbol = Symbol("expecting");
 pub const FIELD_IDENTIFIER: Symbol = Symbol("field_identifier");
 pub const FLATTEN: Symbol = Symbol("flatten");
 pub const FROM: Symbol = Symbol("from");
 pub const GETTER: Symbol = Symbol("getter");
 pub const INTO: Symbol = Symbol("into");
+pub const KIND: Symbol = Symbol("kind");
+pub const NAME: Symbol = Symbol("name");
 pub const NON_EXHAUSTIVE: Symbol = Symbol("non_exhaustive");
+pub const OMIT_NONE: Symbol = Symbol("omit_none");
 pub const OTHER: Symbol = Symbol("other");
+pub const POLYMORPHIC: Symbol = Symbol("polymorphic");
+pub const RECORD: Symbol = Symbol("record");
 pub const REMOTE: Symbol = Symbol("remote");
 pub const RENAME: Symbol = Symbol("rename");
 pub const RENAME_ALL: Symbol = Symbol("rename_all");
 pub const RENAME_ALL_FIELDS: Symbol = Symbol("rename_all_fields");
 pub const REPR: Symbol = Symbol("repr");
+pub const RESCRIPT: Symbol = Symbol("rescript");
+pub const RESCRIPT_RESULT: Symbol = Symbol("rescript_result");
+pub const RESULT: Symbol = Symbol("result");
+pub const SCHEMA: Symbol = Symbol("schema");
 pub const SERDE: Symbol = Symbol("serde");
 pub const SERIALIZE: Symbol = Symbol("serialize");
 pub const SERIALIZE_WITH: Symbol = Symbol("serialize_with");
@@ -34,8 +45,11 @@ pub const SKIP_DESERIALIZING: Symbol = Symbol("skip_deserializing");
 pub const SKIP_SERIALIZING: Symbol = Symbol("skip_serializing");
 pub const SKIP_SERIALIZING_IF: Symbol = Symbol("skip_serializing_if");
 pub const TAG: Symbol = Symbol("tag");
+pub const TAG_VALUE: Symbol = Symbol("tag_value");
 pub const TRANSPARENT: Symbol = Symbol("transparent");
 pub const TRY_FROM: Symbol = Symbol("try_from");
+pub const TYPE: Symbol = Symbol("type");
+pub const UNBOXED: Symbol = Symbol("unboxed");
 pub const UNTAGGED: Symbol = Symbol("untagged");
 pub const VARIANT_IDENTIFIER: Symbol = Symbol("variant_identifier");
 pub const WITH: Symbol = Symbol("with");
@@ -84,22 +84,21 @@ mod fragment;
 mod de;
 mod deprecated;
 mod dummy;
+mod export;
 mod pretend;
 mod rescript;
 mod ser;
 mod this;
 
-// Serde 1.0.X exports __privateX where X is the patch version.
-// Our crate version matches serde's, so we use CARGO_PKG_VERSION_PATCH.
+// ReScript: serde's own private module is versioned (`__private228`, ...) and
+// only works with the serde_derive of the same release. Generated code uses
+// the copy in `serde_derive_rescript_rt` instead (see `dummy::wrap_in_const`).
 #[allow(non_camel_case_types)]
 struct private;
 
 impl private {
     fn ident(&self) -> Ident {
-        Ident::new(
-            concat!("__private", env!("CARGO_PKG_VERSION_PATCH")),
-            Span::call_site(),
-        )
+        Ident::new("__private", Span::call_site())
     }
 }
 
@@ -109,7 +108,7 @@ impl ToTokens for private {
     }
 }
 
-#[proc_macro_derive(SerializeDto, attributes(serde))]
+#[proc_macro_derive(SerializeDto, attributes(serde, rescript))]
 pub fn derive_serialize(input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as DeriveInput);
     ser::expand_derive_serialize(&mut input)
@@ -117,10 +116,18 @@ pub fn derive_serialize(input: TokenStream) -> TokenStream {
         .into()
 }
 
-#[proc_macro_derive(DeserializeDto, attributes(serde))]
+#[proc_macro_derive(DeserializeDto, attributes(serde, rescript))]
 pub fn derive_deserialize(input: TokenStream) -> TokenStream {
     let mut input = parse_macro_input!(input as DeriveInput);
     de::expand_derive_deserialize(&mut input)
         .unwrap_or_else(syn::Error::into_compile_error)
         .into()
 }
+
+#[proc_macro_derive(RescriptType, attributes(serde, rescript))]
+pub fn derive_rescript_type(input: TokenStream) -> TokenStream {
+    let mut input = parse_macro_input!(input as DeriveInput);
+    export::expand_derive_rescript_type(&mut input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
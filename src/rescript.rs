@@ -1,20 +1,117 @@
 //! Utilities for handling enums with ReScript-style serialization.
 
-use crate::internals::ast::{Style, Variant};
-use crate::internals::attr;
+use crate::internals::ast::{Field, Style, Variant};
+use crate::internals::{attr, ungroup, RenameRule};
+use std::fmt::{self, Display};
+use syn::ext::IdentExt as _;
+use syn::{Member, Type};
 
 /// Default tag field name for internally tagged struct variants in enums - matches ReSript one.
 pub const DEFAULT_TAG: &str = "TAG";
 
-/// Determines if an enum is "mixed" - containing both unit and struct variants.
+/// Determines if an enum is "mixed" - containing both unit variants and variants
+/// with payload (struct, tuple or newtype).
 ///
 /// Mixed enums receive special serialization/deserialization treatment:
 /// - Unit variants are serialized as strings
-/// - Struct variants are serialized with internal tagging
+/// - Variants with payload are serialized with internal tagging
 pub fn is_mixed_enum(variants: &[Variant]) -> bool {
     let has_unit = variants.iter().any(|v| matches!(v.style, Style::Unit));
-    let has_struct = variants.iter().any(|v| matches!(v.style, Style::Struct));
-    has_unit && has_struct
+    let has_payload = variants.iter().any(has_payload);
+    has_unit && has_payload
+}
+
+/// Determines if a variant carries a payload, i.e. it is represented by a
+/// `TAG` object in ReScript.
+///
+/// Struct variants keep their field names, tuple and newtype variants are keyed
+/// by position: `Foo(a, b)` -> `{ "TAG": "Foo", "_0": a, "_1": b }`.
+pub fn has_payload(variant: &Variant) -> bool {
+    match variant.style {
+        Style::Struct | Style::Tuple | Style::Newtype => true,
+        Style::Unit => false,
+    }
+}
+
+/// Determines if any variant is represented by a number (`#[serde(as_int = 1)]`).
+///
+/// Unit variants of such enums are written as bare values even if the enum has
+/// no payload variants, and read back through `enum_mixed`.
+pub fn has_int_variants(variants: &[Variant]) -> bool {
+    variants.iter().any(|v| v.attrs.as_int().is_some())
+}
+
+/// Determines if any variant writes a bool, number or null to the tag field
+/// instead of its name (`#[serde(tag_value = true)]`).
+pub fn has_tag_values(variants: &[Variant]) -> bool {
+    variants.iter().any(|v| v.attrs.tag_value().is_some())
+}
+
+/// Value of the tag field identifying a variant as it appears in JSON, for
+/// error messages: `"Circle"`, or the literal of `#[serde(tag_value = ...)]`.
+pub fn tag_json(variant: &Variant) -> String {
+    match variant.attrs.tag_value() {
+        None => format!("{:?}", variant.attrs.name().deserialize_name().value),
+        Some(attr::TagValue::Bool(value)) => value.to_string(),
+        Some(attr::TagValue::Int(value)) => value.to_string(),
+        Some(attr::TagValue::Float(value)) => float_json(value),
+        Some(attr::TagValue::Null) => "null".to_owned(),
+    }
+}
+
+/// A float as JavaScript writes it: `1` for `1.0`, `-1.5`.
+pub fn float_json(value: f64) -> String {
+    match integral(value) {
+        Some(int) => int.to_string(),
+        None => format!("{:?}", value),
+    }
+}
+
+/// The integer JavaScript writes for a float without a fractional part, e.g.
+/// `1` for `1.0`. `None` for other floats, or floats too large to be exact.
+pub fn integral(value: f64) -> Option<i64> {
+    if value.fract() == 0.0 && value.abs() <= (1u64 << 53) as f64 {
+        Some(value as i64)
+    } else {
+        None
+    }
+}
+
+/// Accepted values of the tag field, for error messages: `"Circle" | "Rect"`.
+/// The `#[serde(other)]` variant is left out.
+pub fn tag_values(variants: &[(usize, &Variant)]) -> String {
+    let values: Vec<_> = variants
+        .iter()
+        .filter(|(_, variant)| !variant.attrs.other())
+        .map(|(_, variant)| tag_json(variant))
+        .collect();
+    values.join(" | ")
+}
+
+/// Describes the tag field of an enum, for error messages:
+/// `TAG of enum Shape: "Circle" | "Rect"`.
+pub fn describe_tag(tag: &str, type_name: &str, variants: &[(usize, &Variant)]) -> String {
+    format!("{} of enum {}: {}", tag, type_name, tag_values(variants))
+}
+
+/// Determines if the ReScript representation of an enum is only used for
+/// human-readable formats. It relies on `deserialize_any` (or writes maps that
+/// can only be read back with it), so formats like bincode get the compact
+/// index-based encoding of externally tagged enums instead.
+pub fn has_compact_fallback(cattrs: &attr::Container, variants: &[Variant]) -> bool {
+    if variants.iter().any(|v| v.attrs.untagged()) {
+        return false;
+    }
+    match cattrs.tag() {
+        attr::TagType::External => {
+            !cattrs.polymorphic()
+                && (is_mixed_enum(variants)
+                    || has_int_variants(variants)
+                    || variants.iter().any(has_payload))
+        }
+        attr::TagType::Internal { .. } => true,
+        attr::TagType::Adjacent { .. } | attr::TagType::None => false,
+    }
 }
 
 /// Gets the effective tag name for enum, respecting explicit `#[serde(tag = "...")]`
@@ -25,3 +122,242 @@ pub fn get_effective_tag(cattrs: &attr::Container) -> String {
         _ => DEFAULT_TAG.to_string(),
     }
 }
+
+/// Runtime kind of a variant payload in a ReScript `@unboxed` variant. An
+/// unboxed variant can have at most one constructor of each kind.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum PayloadKind {
+    /// Unit variants, `@as(null)` in ReScript.
+    Null,
+    Bool,
+    /// ReScript `int` and `float` are both JS numbers.
+    Number,
+    String,
+    Array,
+    /// Records and dicts.
+    Object,
+}
+
+impl PayloadKind {
+    /// Names accepted by `#[rescript(kind = "...")]`.
+    pub const NAMES: &'static str = "null, boolean, number, string, array, object";
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "null" => Some(PayloadKind::Null),
+            "boolean" => Some(PayloadKind::Bool),
+            "number" => Some(PayloadKind::Number),
+            "string" => Some(PayloadKind::String),
+            "array" => Some(PayloadKind::Array),
+            "object" => Some(PayloadKind::Object),
+            _ => None,
+        }
+    }
+}
+
+impl Display for PayloadKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(match self {
+            PayloadKind::Null => "null",
+            PayloadKind::Bool => "boolean",
+            PayloadKind::Number => "number",
+            PayloadKind::String => "string",
+            PayloadKind::Array => "array",
+            PayloadKind::Object => "object",
+        })
+    }
+}
+
+/// Determines the payload kind of a variant of an unboxed enum. `None` if it
+/// can't be told from the variant definition, e.g. for `Option` payloads or
+/// variants with `deserialize_with`, unless declared with
+/// `#[rescript(kind = "...")]`.
+///
+/// Newtype payloads are classified by their type name only, so only known
+/// primitives and collections have a kind.
+pub fn payload_kind(variant: &Variant) -> Option<PayloadKind> {
+    if let Some(kind) = variant.attrs.rescript_kind() {
+        return Some(kind);
+    }
+    if variant.attrs.deserialize_with().is_some() {
+        return None;
+    }
+    match variant.style {
+        Style::Unit => Some(PayloadKind::Null),
+        Style::Newtype if variant.fields[0].attrs.skip_deserializing() => Some(PayloadKind::Null),
+        Style::Newtype => type_kind(variant.fields[0].ty),
+        Style::Tuple => Some(PayloadKind::Array),
+        Style::Struct => Some(PayloadKind::Object),
+    }
+}
+
+/// Gets the inner `Option<T>` of an `Option<Option<T>>` field of an
+/// `omit_none` container, which mirrors ReScript's `option<Nullable.t<'a>>`:
+/// the field is left out for `None`, written as `null` for `Some(None)` and
+/// read back the same way.
+pub fn nullable_field_inner<'a>(field: &Field<'a>) -> Option<&'a Type> {
+    if !field.attrs.omits_none() {
+        return None;
+    }
+    let inner = option_inner(field.ty)?;
+    option_inner(inner)?;
+    Some(inner)
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(ty) = ungroup(ty) else {
+        return None;
+    };
+    let seg = ty.path.segments.last()?;
+    let syn::PathArguments::AngleBracketed(args) = &seg.arguments else {
+        return None;
+    };
+    match args.args.first() {
+        Some(syn::GenericArgument::Type(arg)) if seg.ident == "Option" && args.args.len() == 1 => {
+            Some(arg)
+        }
+        _ => None,
+    }
+}
+
+fn type_kind(ty: &Type) -> Option<PayloadKind> {
+    match ungroup(ty) {
+        Type::Path(ty) => {
+            let seg = ty.path.segments.last()?;
+            match seg.ident.to_string().as_str() {
+                "bool" => Some(PayloadKind::Bool),
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" | "f32" | "f64" => Some(PayloadKind::Number),
+                "String" | "str" | "char" => Some(PayloadKind::String),
+                "Vec" | "VecDeque" | "LinkedList" | "HashSet" | "BTreeSet" | "BinaryHeap" => {
+                    Some(PayloadKind::Array)
+                }
+                "HashMap" | "BTreeMap" => Some(PayloadKind::Object),
+                "Box" | "Rc" | "Arc" | "Cow" => match &seg.arguments {
+                    syn::PathArguments::AngleBracketed(args) => {
+                        args.args.iter().find_map(|arg| match arg {
+                            syn::GenericArgument::Type(ty) => type_kind(ty),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                },
+                _ => None,
+            }
+        }
+        Type::Reference(ty) => type_kind(&ty.elem),
+        Type::Paren(ty) => type_kind(&ty.elem),
+        Type::Slice(_) | Type::Array(_) => Some(PayloadKind::Array),
+        Type::Tuple(ty) if ty.elems.is_empty() => Some(PayloadKind::Null),
+        Type::Tuple(_) => Some(PayloadKind::Array),
+        _ => None,
+    }
+}
+
+/// Keywords of the ReScript syntax, which can't be used as record labels.
+const KEYWORDS: &[&str] = &[
+    "and",
+    "as",
+    "assert",
+    "async",
+    "await",
+    "constraint",
+    "else",
+    "exception",
+    "external",
+    "false",
+    "for",
+    "if",
+    "in",
+    "include",
+    "lazy",
+    "let",
+    "module",
+    "mutable",
+    "of",
+    "open",
+    "private",
+    "rec",
+    "switch",
+    "true",
+    "try",
+    "type",
+    "when",
+    "while",
+];
+
+pub fn is_keyword(name: &str) -> bool {
+    KEYWORDS.contains(&name)
+}
+
+/// ReScript record label of a field before escaping: its Rust name in
+/// camelCase, i.e. its serialized name unless renamed, `_0`, `_1`, ... for
+/// positional members.
+pub fn field_label(field: &Field) -> String {
+    match &field.member {
+        Member::Named(ident) => RenameRule::CamelCase.apply_to_field(&ident.unraw().to_string()),
+        Member::Unnamed(index) => format!("_{}", index.index),
+    }
+}
+
+/// Whether `name` can be written as a record label: a lowercase identifier
+/// other than `_` that is not a keyword.
+pub fn is_label(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_lowercase() || ch == '_')
+        && name != "_"
+        && is_ident_rest(name)
+        && !is_keyword(name)
+}
+
+/// Whether `name` can be written as a variant constructor: a capitalized
+/// identifier.
+pub fn is_constructor(name: &str) -> bool {
+    name.starts_with(|ch: char| ch.is_ascii_uppercase()) && is_ident_rest(name)
+}
+
+/// Closest valid record label to `name`: `type` -> `type_`,
+/// `first-name` -> `first_name`, `Id` -> `id`, `1st` -> `_1st`.
+pub fn escape_label(name: &str) -> String {
+    let mut label = replace_invalid_chars(name);
+    if let Some(first) = label.get(..1) {
+        label.replace_range(..1, &first.to_ascii_lowercase());
+    }
+    if !label.starts_with(|ch: char| ch.is_ascii_lowercase() || ch == '_') {
+        label.insert(0, '_');
+    }
+    if label == "_" || is_keyword(&label) {
+        label.push('_');
+    }
+    label
+}
+
+/// Closest valid variant constructor to `name`: `active` -> `Active`,
+/// `_Hidden` -> `V_Hidden`.
+pub fn escape_constructor(name: &str) -> String {
+    let mut constructor = replace_invalid_chars(name);
+    if let Some(first) = constructor.get(..1) {
+        constructor.replace_range(..1, &first.to_ascii_uppercase());
+    }
+    if !constructor.starts_with(|ch: char| ch.is_ascii_uppercase()) {
+        constructor.insert(0, 'V');
+    }
+    constructor
+}
+
+fn is_ident_rest(name: &str) -> bool {
+    name.chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '\'')
+}
+
+/// Replaces every character that can't appear in an identifier with `_`.
+fn replace_invalid_chars(name: &str) -> String {
+    name.chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || ch == '_' || ch == '\'' {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
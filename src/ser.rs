@@ -1,5 +1,5 @@
 use crate::deprecated::allow_deprecated;
-use crate::fragment::{Fragment, Match, Stmts};
+use crate::fragment::{Expr, Fragment, Match, Stmts};
 use crate::internals::ast::{Container, Data, Field, Style, Variant};
 use crate::internals::name::Name;
 use crate::internals::{attr, replace_receiver, Ctxt, Derive};
@@ -41,6 +41,20 @@ pub fn expand_derive_serialize(input: &mut syn::DeriveInput) -> syn::Result<Toke
                 }
             }
         }
+    } else if cont.attrs.dto() {
+        // ReScript: `Serialize` is left to upstream serde
+        quote! {
+            #[automatically_derived]
+            #allow_deprecated
+            impl #impl_generics _serde::#private::SerializeDto for #ident #ty_generics #where_clause {
+                fn serialize_dto<__S>(&self, __serializer: __S) -> _serde::#private::Result<__S::Ok, __S::Error>
+                where
+                    __S: _serde::Serializer,
+                {
+                    #body
+                }
+            }
+        }
     } else {
         quote! {
             #[automatically_derived]
@@ -155,10 +169,11 @@ fn build_generics(cont: &Container) -> syn::Generics {
 // belong to a variant with a `skip_serializing` or `serialize_with` attribute,
 // are not serialized by us so we do not generate a bound. Fields with a `bound`
 // attribute specify their own bound so we do not generate one. All other fields
-// may need a `T: Serialize` bound where T is the type of the field.
+// may need a `T: Serialize` bound where T is the type of the field, including
+// `rescript_result` fields, whose functions are generic over the `Result`.
 fn needs_serialize_bound(field: &attr::Field, variant: Option<&attr::Variant>) -> bool {
     !field.skip_serializing()
-        && field.serialize_with().is_none()
+        && (field.serialize_with().is_none() || field.rescript_result())
         && field.ser_bound().is_none()
         && variant.map_or(true, |variant| {
             !variant.skip_serializing()
@@ -345,11 +360,11 @@ fn serialize_struct_as_struct(
     let let_mut = mut_if(serialized_fields.peek().is_some() || tag_field_exists);
 
     let len = serialized_fields
-        .map(|field| match field.attrs.skip_serializing_if() {
-            None => quote!(1),
-            Some(path) => {
-                let field_expr = get_member(params, field, &field.member);
-                quote!(if #path(#field_expr) { 0 } else { 1 })
+        .map(|field| {
+            let field_expr = get_member(params, field, &field.member);
+            match skip_condition(field, &field_expr) {
+                None => quote!(1),
+                Some(skip) => quote!(if #skip { 0 } else { 1 }),
             }
         })
         .fold(
@@ -357,7 +372,10 @@ fn serialize_struct_as_struct(
             |sum, expr| quote!(#sum + #expr),
         );
 
+    let human_readable = human_readable_binding(fields);
+
     quote_block! {
+        #human_readable
         let #let_mut __serde_state = _serde::Serializer::serialize_struct(__serializer, #type_name, #len)?;
         #tag_field
         #(#serialize_fields)*
@@ -383,7 +401,10 @@ fn serialize_struct_as_map(
 
     let let_mut = mut_if(serialized_fields.peek().is_some() || tag_field_exists);
 
+    let human_readable = human_readable_binding(fields);
+
     quote_block! {
+        #human_readable
         let #let_mut __serde_state = _serde::Serializer::serialize_map(__serializer, _serde::#private::None)?;
         #tag_field
         #(#serialize_fields)*
@@ -396,6 +417,8 @@ fn serialize_enum(params: &Parameters, variants: &[Variant], cattrs: &attr::Cont
 
     // ReScript: detect mixed enums (both unit and struct variants)
     let is_mixed = rescript::is_mixed_enum(variants);
+    let is_int = rescript::has_int_variants(variants);
+    let is_compact = rescript::has_compact_fallback(cattrs, variants);
 
     let self_var = &params.self_var;
 
@@ -403,7 +426,15 @@ fn serialize_enum(params: &Parameters, variants: &[Variant], cattrs: &attr::Cont
         .iter()
         .enumerate()
         .map(|(variant_index, variant)| {
-            serialize_variant(params, variant, variant_index as u32, cattrs, is_mixed)
+            serialize_variant(
+                params,
+                variant,
+                variant_index as u32,
+                cattrs,
+                is_mixed,
+                is_int,
+                is_compact,
+            )
         })
         .collect();
 
@@ -426,6 +457,8 @@ fn serialize_variant(
     variant_index: u32,
     cattrs: &attr::Container,
     is_mixed_enum: bool,
+    is_int_enum: bool,
+    is_compact_enum: bool,
 ) -> TokenStream {
     let this_value = &params.this_value;
     let variant_ident = &variant.ident;
@@ -475,44 +508,35 @@ fn serialize_variant(
             }
         };
 
-        let body = Match(match (cattrs.tag(), variant.attrs.untagged()) {
+        let body = match (cattrs.tag(), variant.attrs.untagged()) {
             (attr::TagType::External, false) => {
-                // ReScript: handle mixed enums and struct-only enums specially
-                if is_mixed_enum {
-                    match variant.style {
-                        Style::Unit => {
-                            // Unit variants in mixed enums serialize as strings
-                            let variant_name = variant.attrs.name().serialize_name();
-                            quote_expr! {
-                                _serde::Serializer::serialize_str(__serializer, #variant_name)
-                            }
-                        }
-                        Style::Struct => {
-                            // Struct variants in mixed enums use internal tagging with TAG
-                            let tag = rescript::get_effective_tag(cattrs);
-                            serialize_internally_tagged_variant(params, variant, cattrs, &tag)
-                        }
-                        _ => {
-                            // Newtype/Tuple: fall back to external tagging
-                            serialize_externally_tagged_variant(
-                                params,
-                                variant,
-                                variant_index,
-                                cattrs,
-                            )
+                // ReScript: unit variants of mixed and polymorphic enums
+                // serialize as strings (or numbers with `as_int`), variants
+                // with payload use internal tagging with TAG
+                match effective_style(variant) {
+                    Style::Unit if variant.attrs.as_int().is_some() => {
+                        serialize_int_variant(variant)
+                    }
+                    Style::Unit if is_mixed_enum || is_int_enum || cattrs.polymorphic() => {
+                        let variant_name = variant.attrs.name().serialize_name();
+                        quote_expr! {
+                            _serde::Serializer::serialize_str(__serializer, #variant_name)
                         }
                     }
-                } else if matches!(variant.style, Style::Struct) {
-                    // Non-mixed struct variants also use internal tagging with TAG
-                    let tag = rescript::get_effective_tag(cattrs);
-                    serialize_internally_tagged_variant(params, variant, cattrs, &tag)
-                } else {
-                    serialize_externally_tagged_variant(params, variant, variant_index, cattrs)
+                    _ if rescript::has_payload(variant) => {
+                        let tag = rescript::get_effective_tag(cattrs);
+                        serialize_internally_tagged_variant(params, variant, cattrs, &tag)
+                    }
+                    _ => {
+                        serialize_externally_tagged_variant(params, variant, variant_index, cattrs)
+                    }
                 }
             }
             (attr::TagType::Internal { tag }, false) => {
                 // ReScript: in mixed enums, unit variants serialize as plain strings
-                if is_mixed_enum && matches!(variant.style, Style::Unit) {
+                if is_mixed_enum && variant.attrs.as_int().is_some() {
+                    serialize_int_variant(variant)
+                } else if is_mixed_enum && matches!(variant.style, Style::Unit) {
                     let variant_name = variant.attrs.name().serialize_name();
                     quote_expr! {
                         _serde::Serializer::serialize_str(__serializer, #variant_name)
@@ -534,7 +558,28 @@ fn serialize_variant(
             (attr::TagType::None, _) | (_, true) => {
                 serialize_untagged_variant(params, variant, cattrs)
             }
-        });
+        };
+
+        // ReScript: formats that are not human-readable get the compact
+        // externally tagged encoding
+        let body = if is_compact_enum {
+            let compact = Expr(serialize_externally_tagged_variant(
+                params,
+                variant,
+                variant_index,
+                cattrs,
+            ));
+            let body = Expr(body);
+            Match(quote_expr! {
+                if _serde::Serializer::is_human_readable(&__serializer) {
+                    #body
+                } else {
+                    #compact
+                }
+            })
+        } else {
+            Match(body)
+        };
 
         quote! {
             #case => #body
@@ -542,6 +587,32 @@ fn serialize_variant(
     }
 }
 
+fn serialize_int_variant(variant: &Variant) -> Fragment {
+    let value = variant.attrs.as_int();
+    quote_expr! {
+        _serde::Serializer::serialize_i64(__serializer, #value)
+    }
+}
+
+// Value written to the tag field: the variant name, or the typed literal from
+// `#[serde(tag_value = ...)]`.
+fn serialize_tag_value(variant: &Variant) -> TokenStream {
+    match variant.attrs.tag_value() {
+        None => {
+            let variant_name = variant.attrs.name().serialize_name();
+            quote!(#variant_name)
+        }
+        Some(attr::TagValue::Bool(value)) => quote!(&#value),
+        Some(attr::TagValue::Int(value)) => quote!(&#value),
+        // Written like JavaScript does, `1` for `1.0`
+        Some(attr::TagValue::Float(value)) => match rescript::integral(value) {
+            Some(int) => quote!(&#int),
+            None => quote!(&#value),
+        },
+        Some(attr::TagValue::Null) => quote!(&()),
+    }
+}
+
 fn serialize_externally_tagged_variant(
     params: &Parameters,
     variant: &Variant,
@@ -641,43 +712,26 @@ fn serialize_internally_tagged_variant(
         };
     }
 
+    let tag_value = serialize_tag_value(variant);
+
     match effective_style(variant) {
         Style::Unit => {
             quote_block! {
                 let mut __struct = _serde::Serializer::serialize_struct(
                     __serializer, #type_name, 1)?;
                 _serde::ser::SerializeStruct::serialize_field(
-                    &mut __struct, #tag, #variant_name)?;
+                    &mut __struct, #tag, #tag_value)?;
                 _serde::ser::SerializeStruct::end(__struct)
             }
         }
-        Style::Newtype => {
-            let field = &variant.fields[0];
-            let mut field_expr = quote!(__field0);
-            if let Some(path) = field.attrs.serialize_with() {
-                field_expr = wrap_serialize_field_with(params, field.ty, path, &field_expr);
-            }
-
-            let span = field.original.span();
-            let func = quote_spanned!(span=> _serde::#private::ser::serialize_tagged_newtype);
-            quote_expr! {
-                #func(
-                    __serializer,
-                    #enum_ident_str,
-                    #variant_ident_str,
-                    #tag,
-                    #variant_name,
-                    #field_expr,
-                )
-            }
-        }
-        Style::Struct => serialize_struct_variant(
-            StructVariant::InternallyTagged { tag, variant_name },
+        // ReScript: tuple and newtype payloads are written next to the tag,
+        // keyed by position (`_0`, `_1`, ...)
+        Style::Struct | Style::Tuple | Style::Newtype => serialize_struct_variant(
+            StructVariant::InternallyTagged { tag, tag_value },
             params,
             &variant.fields,
             type_name,
         ),
-        Style::Tuple => unreachable!("checked in serde_derive_internals"),
     }
 }
 
@@ -919,7 +973,7 @@ enum StructVariant<'a> {
     },
     InternallyTagged {
         tag: &'a str,
-        variant_name: &'a Name,
+        tag_value: TokenStream,
     },
     Untagged,
 }
@@ -952,21 +1006,24 @@ fn serialize_struct_variant(
 
     let len = serialized_fields
         .map(|field| {
-            let member = &field.member;
+            let member = variant_field_ident(&field.member);
 
-            match field.attrs.skip_serializing_if() {
-                Some(path) => quote!(if #path(#member) { 0 } else { 1 }),
+            match skip_condition(field, &quote!(#member)) {
+                Some(skip) => quote!(if #skip { 0 } else { 1 }),
                 None => quote!(1),
             }
         })
         .fold(quote!(0), |sum, expr| quote!(#sum + #expr));
 
+    let human_readable = human_readable_binding(fields);
+
     match context {
         StructVariant::ExternallyTagged {
             variant_index,
             variant_name,
         } => {
             quote_block! {
+                #human_readable
                 let #let_mut __serde_state = _serde::Serializer::serialize_struct_variant(
                     __serializer,
                     #name,
@@ -978,8 +1035,9 @@ fn serialize_struct_variant(
                 _serde::ser::SerializeStructVariant::end(__serde_state)
             }
         }
-        StructVariant::InternallyTagged { tag, variant_name } => {
+        StructVariant::InternallyTagged { tag, tag_value } => {
             quote_block! {
+                #human_readable
                 let mut __serde_state = _serde::Serializer::serialize_struct(
                     __serializer,
                     #name,
@@ -988,7 +1046,7 @@ fn serialize_struct_variant(
                 _serde::ser::SerializeStruct::serialize_field(
                     &mut __serde_state,
                     #tag,
-                    #variant_name,
+                    #tag_value,
                 )?;
                 #(#serialize_fields)*
                 _serde::ser::SerializeStruct::end(__serde_state)
@@ -996,6 +1054,7 @@ fn serialize_struct_variant(
         }
         StructVariant::Untagged => {
             quote_block! {
+                #human_readable
                 let #let_mut __serde_state = _serde::Serializer::serialize_struct(
                     __serializer,
                     #name,
@@ -1024,6 +1083,8 @@ fn serialize_struct_variant_with_flatten(
 
     let let_mut = mut_if(serialized_fields.peek().is_some());
 
+    let human_readable = human_readable_binding(fields);
+
     match context {
         StructVariant::ExternallyTagged {
             variant_index,
@@ -1051,6 +1112,7 @@ fn serialize_struct_variant_with_flatten(
                         __S: _serde::Serializer,
                     {
                         let (#(#members,)*) = self.data;
+                        #human_readable
                         let #let_mut __serde_state = _serde::Serializer::serialize_map(
                             __serializer,
                             _serde::#private::None)?;
@@ -1070,15 +1132,16 @@ fn serialize_struct_variant_with_flatten(
                     })
             }
         }
-        StructVariant::InternallyTagged { tag, variant_name } => {
+        StructVariant::InternallyTagged { tag, tag_value } => {
             quote_block! {
+                #human_readable
                 let #let_mut __serde_state = _serde::Serializer::serialize_map(
                     __serializer,
                     _serde::#private::None)?;
                 _serde::ser::SerializeMap::serialize_entry(
                     &mut __serde_state,
                     #tag,
-                    #variant_name,
+                    #tag_value,
                 )?;
                 #(#serialize_fields)*
                 _serde::ser::SerializeMap::end(__serde_state)
@@ -1086,6 +1149,7 @@ fn serialize_struct_variant_with_flatten(
         }
         StructVariant::Untagged => {
             quote_block! {
+                #human_readable
                 let #let_mut __serde_state = _serde::Serializer::serialize_map(
                     __serializer,
                     _serde::#private::None)?;
@@ -1144,6 +1208,35 @@ fn serialize_tuple_struct_visitor(
         .collect()
 }
 
+/// Condition of `skip_serializing_if` on a field. ReScript: fields left out
+/// by `omit_none` are only skipped when the serializer is human-readable,
+/// which is bound to `__serde_human_readable` by `human_readable_binding`.
+fn skip_condition(field: &Field, field_expr: &TokenStream) -> Option<TokenStream> {
+    let path = field.attrs.skip_serializing_if()?;
+    if field.attrs.skip_if_human_readable() {
+        Some(quote!((__serde_human_readable && #path(#field_expr))))
+    } else {
+        Some(quote!(#path(#field_expr)))
+    }
+}
+
+/// ReScript: binds `__serde_human_readable` before `__serializer` is consumed,
+/// if any field needs it.
+fn human_readable_binding(fields: &[Field]) -> Option<TokenStream> {
+    let needed = fields.iter().any(|field| {
+        !field.attrs.skip_serializing()
+            && field.attrs.skip_serializing_if().is_some()
+            && field.attrs.skip_if_human_readable()
+    });
+    if needed {
+        Some(quote! {
+            let __serde_human_readable = _serde::Serializer::is_human_readable(&__serializer);
+        })
+    } else {
+        None
+    }
+}
+
 fn serialize_struct_visitor(
     fields: &[Field],
     params: &Parameters,
@@ -1157,17 +1250,15 @@ fn serialize_struct_visitor(
             let member = &field.member;
 
             let mut field_expr = if is_enum {
-                quote!(#member)
+                let id = variant_field_ident(member);
+                quote!(#id)
             } else {
                 get_member(params, field, member)
             };
 
             let key_expr = field.attrs.name().serialize_name();
 
-            let skip = field
-                .attrs
-                .skip_serializing_if()
-                .map(|path| quote!(#path(#field_expr)));
+            let skip = skip_condition(field, &field_expr);
 
             if let Some(path) = field.attrs.serialize_with() {
                 field_expr = wrap_serialize_field_with(params, field.ty, path, &field_expr);
@@ -1229,12 +1320,7 @@ fn wrap_serialize_variant_with(
         .fields
         .iter()
         .map(|field| {
-            let id = match &field.member {
-                Member::Named(ident) => ident.clone(),
-                Member::Unnamed(member) => {
-                    Ident::new(&format!("__field{}", member.index), Span::call_site())
-                }
-            };
+            let id = variant_field_ident(&field.member);
             quote!(#id)
         })
         .collect();
@@ -1347,6 +1433,17 @@ fn get_member(params: &Parameters, field: &Field, member: &Member) -> TokenStrea
     }
 }
 
+// Name of the binding that holds a field of the matched enum variant: the field
+// name itself for struct variants and `__fieldN` for tuple and newtype variants.
+fn variant_field_ident(member: &Member) -> Ident {
+    match member {
+        Member::Named(ident) => ident.clone(),
+        Member::Unnamed(member) => {
+            Ident::new(&format!("__field{}", member.index), Span::call_site())
+        }
+    }
+}
+
 fn effective_style(variant: &Variant) -> Style {
     match variant.style {
         Style::Newtype if variant.fields[0].attrs.skip_serializing() => Style::Unit,
@@ -356,3 +356,931 @@ fn test_enum_with_untagged_unnamed_member_deserialization() {
         EnumWithUntaggedUnnamedMember::Unnamed(ChildEnumForUntaggedUnnamedEnum::B { x: 42 })
     );
 }
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum EnumWithPositionalMembers {
+    Unit,
+    Newtype(usize),
+    Tuple(usize, String),
+    Named { field: String },
+}
+
+#[test]
+fn test_enum_newtype_member_serialization() {
+    let value = EnumWithPositionalMembers::Newtype(42);
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "TAG": "Newtype", "_0": 42 }));
+}
+
+#[test]
+fn test_enum_newtype_member_deserialization() {
+    let value = json!({ "TAG": "Newtype", "_0": 42 });
+    let deserialized: EnumWithPositionalMembers = json::from_value(value).unwrap();
+    assert_eq!(deserialized, EnumWithPositionalMembers::Newtype(42));
+}
+
+#[test]
+fn test_enum_tuple_member_serialization() {
+    let value = EnumWithPositionalMembers::Tuple(42, "x".to_string());
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "TAG": "Tuple", "_0": 42, "_1": "x" }));
+}
+
+#[test]
+fn test_enum_tuple_member_deserialization() {
+    let value = json!({ "TAG": "Tuple", "_0": 42, "_1": "x" });
+    let deserialized: EnumWithPositionalMembers = json::from_value(value).unwrap();
+    assert_eq!(
+        deserialized,
+        EnumWithPositionalMembers::Tuple(42, "x".to_string()),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(rename_all_fields = "kebab-case")]
+enum EnumWithOnlyPositionalMembers {
+    Newtype(ChildEnumForUntaggedUnnamedEnum),
+    Tuple(usize, Option<String>),
+}
+
+#[test]
+fn test_enum_with_only_positional_members_serialization() {
+    let value = EnumWithOnlyPositionalMembers::Newtype(ChildEnumForUntaggedUnnamedEnum::B { x: 1 });
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(
+        serialized,
+        json!({ "TAG": "Newtype", "_0": { "TAG": "B", "x": 1 } }),
+    );
+
+    let value = EnumWithOnlyPositionalMembers::Tuple(42, None);
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "TAG": "Tuple", "_0": 42, "_1": null }));
+}
+
+#[test]
+fn test_enum_with_only_positional_members_deserialization() {
+    let value = json!({ "TAG": "Newtype", "_0": "A" });
+    let deserialized: EnumWithOnlyPositionalMembers = json::from_value(value).unwrap();
+    assert_eq!(
+        deserialized,
+        EnumWithOnlyPositionalMembers::Newtype(ChildEnumForUntaggedUnnamedEnum::A),
+    );
+
+    let value = json!({ "TAG": "Tuple", "_0": 42 });
+    let deserialized: EnumWithOnlyPositionalMembers = json::from_value(value).unwrap();
+    assert_eq!(deserialized, EnumWithOnlyPositionalMembers::Tuple(42, None));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(tag = "T")]
+enum EnumWithPositionalMembersAndCustomTag {
+    Unit,
+    Tuple(usize, usize),
+}
+
+#[test]
+fn test_enum_tuple_member_with_custom_tag_serialization() {
+    let value = EnumWithPositionalMembersAndCustomTag::Tuple(1, 2);
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "T": "Tuple", "_0": 1, "_1": 2 }));
+}
+
+#[test]
+fn test_enum_tuple_member_with_custom_tag_deserialization() {
+    let value = json!({ "T": "Tuple", "_0": 1, "_1": 2 });
+    let deserialized: EnumWithPositionalMembersAndCustomTag = json::from_value(value).unwrap();
+    assert_eq!(
+        deserialized,
+        EnumWithPositionalMembersAndCustomTag::Tuple(1, 2),
+    );
+}
+
+// Round-trip matrix: every tag type × every variant style, for mixed enums
+// (unit variants + variants with payload) and payload-only enums.
+
+fn assert_round_trip<T>(value: T, expected: json::Value)
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, expected);
+    let deserialized: T = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum ExternalMixed {
+    Unit,
+    Newtype(usize),
+    Tuple(usize, String),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_external_mixed() {
+    assert_round_trip(ExternalMixed::Unit, json!("Unit"));
+    assert_round_trip(
+        ExternalMixed::Newtype(1),
+        json!({ "TAG": "Newtype", "_0": 1 }),
+    );
+    assert_round_trip(
+        ExternalMixed::Tuple(1, "x".to_string()),
+        json!({ "TAG": "Tuple", "_0": 1, "_1": "x" }),
+    );
+    assert_round_trip(
+        ExternalMixed::Struct { field_a: 1 },
+        json!({ "TAG": "Struct", "fieldA": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum ExternalPayloadOnly {
+    Newtype(usize),
+    Tuple(usize, String),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_external_payload_only() {
+    assert_round_trip(
+        ExternalPayloadOnly::Newtype(1),
+        json!({ "TAG": "Newtype", "_0": 1 }),
+    );
+    assert_round_trip(
+        ExternalPayloadOnly::Tuple(1, "x".to_string()),
+        json!({ "TAG": "Tuple", "_0": 1, "_1": "x" }),
+    );
+    assert_round_trip(
+        ExternalPayloadOnly::Struct { field_a: 1 },
+        json!({ "TAG": "Struct", "fieldA": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum ExternalUnitOnly {
+    A,
+    B,
+}
+
+#[test]
+fn test_round_trip_external_unit_only() {
+    assert_round_trip(ExternalUnitOnly::A, json!("A"));
+    assert_round_trip(ExternalUnitOnly::B, json!("B"));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(tag = "kind")]
+enum InternalMixed {
+    Unit,
+    Newtype(usize),
+    Tuple(usize, String),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_internal_mixed() {
+    assert_round_trip(InternalMixed::Unit, json!("Unit"));
+    assert_round_trip(
+        InternalMixed::Newtype(1),
+        json!({ "kind": "Newtype", "_0": 1 }),
+    );
+    assert_round_trip(
+        InternalMixed::Tuple(1, "x".to_string()),
+        json!({ "kind": "Tuple", "_0": 1, "_1": "x" }),
+    );
+    assert_round_trip(
+        InternalMixed::Struct { field_a: 1 },
+        json!({ "kind": "Struct", "fieldA": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(tag = "kind")]
+enum InternalPayloadOnly {
+    Newtype(usize),
+    Tuple(usize, String),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_internal_payload_only() {
+    assert_round_trip(
+        InternalPayloadOnly::Newtype(1),
+        json!({ "kind": "Newtype", "_0": 1 }),
+    );
+    assert_round_trip(
+        InternalPayloadOnly::Tuple(1, "x".to_string()),
+        json!({ "kind": "Tuple", "_0": 1, "_1": "x" }),
+    );
+    assert_round_trip(
+        InternalPayloadOnly::Struct { field_a: 1 },
+        json!({ "kind": "Struct", "fieldA": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(tag = "t", content = "c")]
+enum Adjacent {
+    Unit,
+    Newtype(usize),
+    Tuple(usize, String),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_adjacent() {
+    assert_round_trip(Adjacent::Unit, json!({ "t": "Unit" }));
+    assert_round_trip(Adjacent::Newtype(1), json!({ "t": "Newtype", "c": 1 }));
+    assert_round_trip(
+        Adjacent::Tuple(1, "x".to_string()),
+        json!({ "t": "Tuple", "c": [1, "x"] }),
+    );
+    assert_round_trip(
+        Adjacent::Struct { field_a: 1 },
+        json!({ "t": "Struct", "c": { "fieldA": 1 } }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(untagged)]
+enum Untagged {
+    Unit,
+    Newtype(usize),
+    Tuple(usize, String),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_untagged() {
+    assert_round_trip(Untagged::Unit, json!(null));
+    assert_round_trip(Untagged::Newtype(1), json!(1));
+    assert_round_trip(Untagged::Tuple(1, "x".to_string()), json!([1, "x"]));
+    assert_round_trip(Untagged::Struct { field_a: 1 }, json!({ "fieldA": 1 }));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum MixedWithUntaggedMember {
+    Unit,
+    Struct {
+        field_a: usize,
+    },
+    #[serde(untagged)]
+    Newtype(String),
+}
+
+#[test]
+fn test_round_trip_mixed_with_untagged_member() {
+    assert_round_trip(MixedWithUntaggedMember::Unit, json!("Unit"));
+    assert_round_trip(
+        MixedWithUntaggedMember::Struct { field_a: 1 },
+        json!({ "TAG": "Struct", "fieldA": 1 }),
+    );
+    assert_round_trip(
+        MixedWithUntaggedMember::Newtype("x".to_string()),
+        json!("x"),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum MixedWithSkippedNewtypeField {
+    Unit,
+    Skipped(#[serde(skip)] usize),
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_mixed_with_skipped_newtype_field() {
+    assert_round_trip(MixedWithSkippedNewtypeField::Skipped(0), json!("Skipped"));
+    assert_round_trip(
+        MixedWithSkippedNewtypeField::Struct { field_a: 1 },
+        json!({ "TAG": "Struct", "fieldA": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum PayloadOnlyWithSkippedNewtypeField {
+    Skipped(#[serde(skip)] usize),
+    Newtype(usize),
+}
+
+#[test]
+fn test_round_trip_payload_only_with_skipped_newtype_field() {
+    assert_round_trip(
+        PayloadOnlyWithSkippedNewtypeField::Skipped(0),
+        json!({ "TAG": "Skipped" }),
+    );
+    assert_round_trip(
+        PayloadOnlyWithSkippedNewtypeField::Newtype(1),
+        json!({ "TAG": "Newtype", "_0": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum TupleWithSkippedMiddleField {
+    Tuple(usize, #[serde(skip)] usize, usize),
+    Unit,
+}
+
+#[test]
+fn test_round_trip_tuple_with_skipped_middle_field() {
+    // Keys follow the ReScript constructor `Tuple(int, int)`
+    assert_round_trip(
+        TupleWithSkippedMiddleField::Tuple(1, 0, 3),
+        json!({ "TAG": "Tuple", "_0": 1, "_1": 3 }),
+    );
+}
+
+mod variant_with {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    #[derive(Serialize, Deserialize)]
+    struct Wrapper {
+        value: String,
+    }
+
+    pub fn serialize<S: Serializer>(value: &usize, serializer: S) -> Result<S::Ok, S::Error> {
+        Wrapper {
+            value: value.to_string(),
+        }
+        .serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<usize, D::Error> {
+        let wrapper = Wrapper::deserialize(deserializer)?;
+        wrapper.value.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum MixedWithVariantWith {
+    Unit,
+    #[serde(with = "variant_with")]
+    Newtype(usize),
+}
+
+#[test]
+fn test_round_trip_mixed_with_variant_with() {
+    assert_round_trip(MixedWithVariantWith::Unit, json!("Unit"));
+    assert_round_trip(
+        MixedWithVariantWith::Newtype(1),
+        json!({ "TAG": "Newtype", "value": "1" }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(unboxed)]
+enum Unboxed {
+    Nothing,
+    Flag(bool),
+    Count(f64),
+    Text(String),
+    Pair(u8, String),
+    Point { x_pos: i32, y_pos: i32 },
+}
+
+#[test]
+fn test_round_trip_unboxed() {
+    assert_round_trip(Unboxed::Nothing, json!(null));
+    assert_round_trip(Unboxed::Flag(true), json!(true));
+    assert_round_trip(Unboxed::Count(1.5), json!(1.5));
+    assert_round_trip(Unboxed::Text("text".to_string()), json!("text"));
+    assert_round_trip(Unboxed::Pair(1, "one".to_string()), json!([1, "one"]));
+    assert_round_trip(
+        Unboxed::Point { x_pos: 1, y_pos: 2 },
+        json!({ "xPos": 1, "yPos": 2 }),
+    );
+}
+
+#[test]
+fn test_unboxed_integer_into_number_variant() {
+    let deserialized: Unboxed = json::from_value(json!(3)).unwrap();
+    assert_eq!(deserialized, Unboxed::Count(3.0));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(unboxed)]
+enum UnboxedWithoutString {
+    Count(u32),
+    Items(Vec<String>),
+}
+
+#[test]
+fn test_unboxed_unexpected_kind() {
+    let err = json::from_value::<UnboxedWithoutString>(json!("text")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: string \"text\", expected unboxed enum UnboxedWithoutString",
+    );
+}
+
+#[test]
+fn test_unboxed_payload_error_is_not_swallowed() {
+    let err = json::from_value::<UnboxedWithoutString>(json!([1])).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: integer `1`, expected a string",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct UnboxedPoint {
+    x: i32,
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(unboxed)]
+enum UnboxedDeclaredKinds {
+    #[rescript(kind = "string")]
+    Id(serde_derive_rescript_rt::BigInt<u64>),
+    #[rescript(kind = "object")]
+    Point(UnboxedPoint),
+}
+
+#[test]
+fn test_round_trip_unboxed_declared_kinds() {
+    assert_round_trip(
+        UnboxedDeclaredKinds::Id(serde_derive_rescript_rt::BigInt(1)),
+        json!("1"),
+    );
+    assert_round_trip(
+        UnboxedDeclaredKinds::Point(UnboxedPoint { x: 1 }),
+        json!({ "x": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(polymorphic)]
+enum Polymorphic {
+    Red,
+    #[serde(rename = "red-ish", alias = "reddish")]
+    RedIsh,
+}
+
+#[test]
+fn test_round_trip_polymorphic() {
+    assert_round_trip(Polymorphic::Red, json!("Red"));
+    assert_round_trip(Polymorphic::RedIsh, json!("red-ish"));
+}
+
+#[test]
+fn test_polymorphic_alias_and_unknown_variant() {
+    let deserialized: Polymorphic = json::from_value(json!("reddish")).unwrap();
+    assert_eq!(deserialized, Polymorphic::RedIsh);
+
+    let err = json::from_value::<Polymorphic>(json!("Blue")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "unknown variant `Blue`, expected one of `Red`, `red-ish`, `reddish`",
+    );
+
+    let err = json::from_value::<Polymorphic>(json!({ "TAG": "Red" })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: map, expected polymorphic variant Polymorphic",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(polymorphic)]
+enum PolymorphicWithOther {
+    Known,
+    #[serde(other)]
+    Unknown,
+}
+
+#[test]
+fn test_polymorphic_other() {
+    let deserialized: PolymorphicWithOther = json::from_value(json!("Newer")).unwrap();
+    assert_eq!(deserialized, PolymorphicWithOther::Unknown);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum IntUnitOnly {
+    #[serde(as_int = 0)]
+    Low,
+    #[serde(as_int = 1)]
+    High,
+    Unknown,
+}
+
+#[test]
+fn test_round_trip_int_unit_only() {
+    assert_round_trip(IntUnitOnly::Low, json!(0));
+    assert_round_trip(IntUnitOnly::High, json!(1));
+    assert_round_trip(IntUnitOnly::Unknown, json!("Unknown"));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum IntMixed {
+    #[serde(as_int = -1)]
+    None,
+    Some {
+        value: usize,
+    },
+}
+
+#[test]
+fn test_round_trip_int_mixed() {
+    assert_round_trip(IntMixed::None, json!(-1));
+    assert_round_trip(
+        IntMixed::Some { value: 1 },
+        json!({ "TAG": "Some", "value": 1 }),
+    );
+}
+
+#[test]
+fn test_int_variant_errors() {
+    let err = json::from_value::<IntUnitOnly>(json!(2)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: integer `2`, expected enum IntUnitOnly: \"Unknown\" | 0 | 1",
+    );
+
+    let err = json::from_value::<IntUnitOnly>(json!("Low")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"Low\", expected enum IntUnitOnly: \"Unknown\" | 0 | 1",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum TypedTag {
+    #[serde(tag_value = true)]
+    Ok { value: usize },
+    #[serde(tag_value = false)]
+    Err(String),
+    #[serde(tag_value = null)]
+    Empty {},
+}
+
+#[test]
+fn test_round_trip_typed_tag() {
+    assert_round_trip(
+        TypedTag::Ok { value: 1 },
+        json!({ "TAG": true, "value": 1 }),
+    );
+    assert_round_trip(
+        TypedTag::Err("boom".to_owned()),
+        json!({ "TAG": false, "_0": "boom" }),
+    );
+    assert_round_trip(TypedTag::Empty {}, json!({ "TAG": null }));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(tag = "kind")]
+enum NumberTag {
+    #[serde(tag_value = 1)]
+    Circle {
+        radius: f64,
+    },
+    #[serde(tag_value = -1.5)]
+    Point {},
+    Square {
+        side: f64,
+    },
+}
+
+#[test]
+fn test_round_trip_number_tag() {
+    assert_round_trip(
+        NumberTag::Circle { radius: 2.0 },
+        json!({ "kind": 1, "radius": 2.0 }),
+    );
+    assert_round_trip(NumberTag::Point {}, json!({ "kind": -1.5 }));
+    assert_round_trip(
+        NumberTag::Square { side: 1.0 },
+        json!({ "kind": "Square", "side": 1.0 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(tag = "kind")]
+enum IntegralTag {
+    #[serde(tag_value = 1.0)]
+    One {},
+    #[serde(tag_value = 2)]
+    Two {},
+}
+
+#[test]
+fn test_integral_number_tag() {
+    // JavaScript writes `1.0` as `1`
+    assert_round_trip(IntegralTag::One {}, json!({ "kind": 1 }));
+    let deserialized: IntegralTag = json::from_str(r#"{"kind":1}"#).unwrap();
+    assert_eq!(deserialized, IntegralTag::One {});
+    let deserialized: IntegralTag = json::from_str(r#"{"kind":1.0}"#).unwrap();
+    assert_eq!(deserialized, IntegralTag::One {});
+    let deserialized: IntegralTag = json::from_str(r#"{"kind":2.0}"#).unwrap();
+    assert_eq!(deserialized, IntegralTag::Two {});
+
+    let err = json::from_value::<IntegralTag>(json!({ "kind": 3 })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: integer `3`, expected kind of enum IntegralTag: 1 | 2",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum TypedTagMixed {
+    Idle,
+    #[serde(tag_value = 0)]
+    Running(usize),
+}
+
+#[test]
+fn test_round_trip_typed_tag_mixed() {
+    assert_round_trip(TypedTagMixed::Idle, json!("Idle"));
+    assert_round_trip(TypedTagMixed::Running(3), json!({ "TAG": 0, "_0": 3 }));
+}
+
+#[test]
+fn test_typed_tag_errors() {
+    let err = json::from_value::<NumberTag>(json!({ "kind": 2 })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: integer `2`, expected kind of enum NumberTag: 1 | -1.5 | \"Square\"",
+    );
+
+    let err = json::from_value::<NumberTag>(json!({ "kind": "Circle" })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"Circle\", expected kind of enum NumberTag: 1 | -1.5 | \"Square\"",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum MixedAliases {
+    #[serde(rename = "idle", alias = "Idle")]
+    Idle,
+    #[serde(alias = "Started")]
+    Running { pid: usize },
+    #[serde(other)]
+    Unknown,
+}
+
+#[test]
+fn test_mixed_aliases() {
+    assert_round_trip(MixedAliases::Idle, json!("idle"));
+    assert_round_trip(
+        MixedAliases::Running { pid: 1 },
+        json!({ "TAG": "Running", "pid": 1 }),
+    );
+
+    let deserialized: MixedAliases = json::from_value(json!("Idle")).unwrap();
+    assert_eq!(deserialized, MixedAliases::Idle);
+
+    let deserialized: MixedAliases =
+        json::from_value(json!({ "TAG": "Started", "pid": 1 })).unwrap();
+    assert_eq!(deserialized, MixedAliases::Running { pid: 1 });
+}
+
+#[test]
+fn test_mixed_other() {
+    let deserialized: MixedAliases = json::from_value(json!("Stopped")).unwrap();
+    assert_eq!(deserialized, MixedAliases::Unknown);
+
+    let deserialized: MixedAliases =
+        json::from_value(json!({ "TAG": "Paused", "reason": "user" })).unwrap();
+    assert_eq!(deserialized, MixedAliases::Unknown);
+}
+
+#[test]
+fn test_mixed_unknown_variant() {
+    let err = json::from_value::<ExternalMixed>(json!("Nope")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"Nope\", expected enum ExternalMixed: \"Unit\" | {\"TAG\": \"Newtype\" | \"Tuple\" | \"Struct\"}",
+    );
+
+    let err = json::from_value::<ExternalMixed>(json!({ "TAG": "Nope" })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"Nope\", expected TAG of enum ExternalMixed: \"Newtype\" | \"Tuple\" | \"Struct\"",
+    );
+}
+
+#[test]
+fn test_mixed_tag_position() {
+    let expected = ExternalMixed::Tuple(1, "x".to_owned());
+
+    let deserialized: ExternalMixed = json::from_str(r#"{"TAG":"Tuple","_0":1,"_1":"x"}"#).unwrap();
+    assert_eq!(deserialized, expected);
+
+    let deserialized: ExternalMixed = json::from_str(r#"{"_0":1,"TAG":"Tuple","_1":"x"}"#).unwrap();
+    assert_eq!(deserialized, expected);
+
+    let err =
+        json::from_str::<ExternalMixed>(r#"{"_0":1,"TAG":"Tuple","TAG":"Tuple"}"#).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate field `TAG` at line 1 column 27");
+
+    let deserialized: MixedAliases =
+        json::from_str(r#"{"TAG":"Paused","reason":"user","since":[1,2]}"#).unwrap();
+    assert_eq!(deserialized, MixedAliases::Unknown);
+}
+
+#[test]
+fn test_mixed_duplicate_tag() {
+    // Buffered, the tag comes after a payload field
+    let err = json::from_str::<ExternalMixed>(r#"{"field_a":1,"TAG":"Struct","TAG":"Newtype"}"#)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "duplicate field `TAG` at line 1 column 33");
+
+    // Streamed, the tag comes first
+    let err = json::from_str::<ExternalMixed>(r#"{"TAG":"Struct","field_a":1,"TAG":"Newtype"}"#)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "duplicate field `TAG` at line 1 column 33");
+
+    let err = json::from_str::<ExternalMixed>(r#"{"TAG":"Tuple","_0":1,"_1":"x","TAG":"Tuple"}"#)
+        .unwrap_err();
+    assert_eq!(err.to_string(), "duplicate field `TAG` at line 1 column 36");
+
+    let err = json::from_str::<MixedAliases>(r#"{"TAG":"Paused","TAG":"Idle"}"#).unwrap_err();
+    assert_eq!(err.to_string(), "duplicate field `TAG` at line 1 column 21");
+}
+
+fn assert_compact_round_trip<T>(value: T, expected: &[u8])
+where
+    T: serde::Serialize + serde::de::DeserializeOwned + PartialEq + std::fmt::Debug,
+{
+    let serialized = bincode::serialize(&value).unwrap();
+    assert_eq!(serialized, expected);
+    let deserialized: T = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[test]
+fn test_compact_round_trip() {
+    assert_compact_round_trip(ExternalMixed::Unit, &[0, 0, 0, 0]);
+    assert_compact_round_trip(
+        ExternalMixed::Newtype(1),
+        &[1, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_compact_round_trip(
+        ExternalMixed::Struct { field_a: 1 },
+        &[3, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_compact_round_trip(
+        InternalMixed::Tuple(1, "x".to_string()),
+        &[
+            2, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, b'x',
+        ],
+    );
+    assert_compact_round_trip(
+        InternalPayloadOnly::Newtype(1),
+        &[0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0],
+    );
+    assert_compact_round_trip(IntMixed::None, &[0, 0, 0, 0]);
+    assert_compact_round_trip(TypedTag::Empty {}, &[2, 0, 0, 0]);
+    assert_compact_round_trip(
+        Polymorphic::RedIsh,
+        &[
+            7, 0, 0, 0, 0, 0, 0, 0, b'r', b'e', b'd', b'-', b'i', b's', b'h',
+        ],
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(rename_all_fields = "none")]
+enum EnumWithRustFieldNames {
+    Struct { field_a: usize },
+}
+
+#[test]
+fn test_round_trip_rust_field_names() {
+    assert_round_trip(
+        EnumWithRustFieldNames::Struct { field_a: 1 },
+        json!({ "TAG": "Struct", "field_a": 1 }),
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(omit_none)]
+enum EnumWithOmittedNone {
+    Idle,
+    Struct { field_a: Option<usize> },
+    Tuple(Option<usize>, usize),
+}
+
+#[test]
+fn test_round_trip_omitted_none() {
+    assert_round_trip(
+        EnumWithOmittedNone::Struct { field_a: None },
+        json!({ "TAG": "Struct" }),
+    );
+    assert_round_trip(
+        EnumWithOmittedNone::Struct { field_a: Some(1) },
+        json!({ "TAG": "Struct", "fieldA": 1 }),
+    );
+    assert_round_trip(
+        EnumWithOmittedNone::Tuple(None, 1),
+        json!({ "TAG": "Tuple", "_0": null, "_1": 1 }),
+    );
+    assert_compact_round_trip(
+        EnumWithOmittedNone::Struct { field_a: None },
+        &[1, 0, 0, 0, 0],
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum PayloadOnlyWithOptions {
+    Empty {},
+    Named { name: Option<String> },
+}
+
+#[test]
+fn test_tag_kinds() {
+    use serde::de::value::{Error, MapDeserializer};
+    use serde::Deserialize;
+
+    // Tags written as bytes are matched by name
+    let map = MapDeserializer::<_, Error>::new([("TAG", b"Named" as &[u8])].into_iter());
+    let deserialized = PayloadOnlyWithOptions::deserialize(map).unwrap();
+    assert_eq!(deserialized, PayloadOnlyWithOptions::Named { name: None });
+
+    let map = MapDeserializer::<_, Error>::new([("TAG", b"Struct" as &[u8])].into_iter());
+    let deserialized = EnumWithOmittedNone::deserialize(map).unwrap();
+    assert_eq!(deserialized, EnumWithOmittedNone::Struct { field_a: None });
+
+    let map = MapDeserializer::<_, Error>::new([("TAG", b"Other" as &[u8])].into_iter());
+    let err = PayloadOnlyWithOptions::deserialize(map).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"Other\", expected TAG of enum PayloadOnlyWithOptions: \"Empty\" | \"Named\"",
+    );
+
+    // Numbers and booleans are not variant indices, they only match typed tags
+    let err = json::from_value::<PayloadOnlyWithOptions>(json!({ "TAG": 0 })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: integer `0`, expected TAG of enum PayloadOnlyWithOptions: \"Empty\" | \"Named\"",
+    );
+
+    let err = json::from_value::<ExternalMixed>(json!({ "TAG": true, "_0": 1 })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: boolean `true`, expected TAG of enum ExternalMixed: \"Newtype\" | \"Tuple\" | \"Struct\"",
+    );
+}
+
+#[test]
+fn test_enum_errors() {
+    let err = json::from_value::<ExternalMixed>(json!(1)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: integer `1`, expected enum ExternalMixed: \"Unit\" | {\"TAG\": \"Newtype\" | \"Tuple\" | \"Struct\"}",
+    );
+
+    let err = json::from_value::<ExternalPayloadOnly>(json!("Newtype")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid type: string \"Newtype\", expected enum ExternalPayloadOnly: {\"TAG\": \"Newtype\" | \"Tuple\" | \"Struct\"}",
+    );
+
+    let err = json::from_value::<ExternalPayloadOnly>(json!({ "_0": 1 })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "missing field `TAG`, expected enum ExternalPayloadOnly: {\"TAG\": \"Newtype\" | \"Tuple\" | \"Struct\"}",
+    );
+
+    // Upstream serde writes `{"Variant": payload}`
+    let err = json::from_value::<ExternalMixed>(json!({ "Struct": { "fieldA": 1 } })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "missing field `TAG`, expected enum ExternalMixed: \"Unit\" | {\"TAG\": \"Newtype\" | \"Tuple\" | \"Struct\"} (found `{\"Struct\": ...}`, the way upstream serde tags enums)",
+    );
+
+    let err = json::from_value::<EnumWithCustomTag>(json!({ "T": "Nope" })).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"Nope\", expected T of enum EnumWithCustomTag: \"Named\"",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[rescript(unboxed)]
+enum UnboxedNamespace {
+    Nothing,
+    Count(f64),
+    Text(String),
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+enum NamespaceVariants {
+    #[rescript(as_int = 1)]
+    High,
+    #[serde(rename = "on")]
+    #[rescript(tag_value = true)]
+    On { value: u8 },
+}
+
+#[test]
+fn test_round_trip_rescript_namespace() {
+    assert_round_trip(UnboxedNamespace::Nothing, json!(null));
+    assert_round_trip(UnboxedNamespace::Count(1.5), json!(1.5));
+    assert_round_trip(UnboxedNamespace::Text("text".to_owned()), json!("text"));
+    assert_round_trip(NamespaceVariants::High, json!(1));
+    assert_round_trip(
+        NamespaceVariants::On { value: 1 },
+        json!({ "TAG": true, "value": 1 }),
+    );
+}
@@ -0,0 +1,663 @@
+#![allow(dead_code)]
+
+use serde_derive_rescript::{DeserializeDto, RescriptType, SerializeDto};
+use serde_derive_rescript_rt::Collector;
+use std::collections::HashMap;
+use std::time::SystemTime;
+
+#[derive(RescriptType)]
+struct Person {
+    first_name: String,
+    age: u8,
+    balance: f64,
+    #[serde(rename = "e-mail")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(skip)]
+    secret: String,
+    scores: HashMap<String, i32>,
+    address: Address,
+}
+
+#[derive(RescriptType)]
+struct Address {
+    city: String,
+}
+
+#[test]
+fn test_struct_record() {
+    assert_eq!(Person::RESCRIPT_MODULE_NAME, "Person");
+    assert_eq!(
+        Person::rescript_module(),
+        r#"module Person = {
+  @schema
+  type t = {
+    firstName: string,
+    age: int,
+    balance: float,
+    @as("e-mail") email: option<string>,
+    tags: option<array<string>>,
+    scores: dict<int>,
+    address: Address.t,
+  }
+}
+"#,
+    );
+}
+
+#[derive(RescriptType)]
+struct Id(u32);
+
+#[derive(RescriptType)]
+struct Pair(String, bool);
+
+#[derive(RescriptType)]
+struct Marker;
+
+#[test]
+fn test_non_record_structs() {
+    assert_eq!(
+        Id::rescript_module(),
+        "module Id = {\n  @schema\n  type t = int\n}\n"
+    );
+    assert_eq!(
+        Pair::rescript_module(),
+        "module Pair = {\n  @schema\n  type t = (string, bool)\n}\n",
+    );
+    assert_eq!(
+        Marker::rescript_module(),
+        "module Marker = {\n  @schema\n  type t = unit\n}\n",
+    );
+}
+
+#[derive(RescriptType)]
+enum Shape {
+    Empty,
+    #[serde(rename = "dot")]
+    Dot,
+    Circle {
+        radius: f64,
+    },
+    Rect(u32, u32),
+    Named(String),
+    #[serde(skip)]
+    Hidden,
+}
+
+#[test]
+fn test_mixed_enum() {
+    assert_eq!(
+        Shape::rescript_module(),
+        r#"module Shape = {
+  @schema
+  type t =
+    | Empty
+    | @as("dot") Dot
+    | Circle({radius: float})
+    | Rect(int, int)
+    | Named(string)
+}
+"#,
+    );
+}
+
+#[derive(RescriptType)]
+#[serde(tag = "kind")]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(String),
+}
+
+#[derive(RescriptType)]
+#[serde(untagged)]
+enum Value {
+    Nothing,
+    Number(f64),
+    Text(String),
+}
+
+#[test]
+fn test_tag_decorators() {
+    assert_eq!(
+        Event::rescript_module(),
+        r#"module Event = {
+  @schema
+  @tag("kind")
+  type t =
+    | Click({x: int, y: int})
+    | Key(string)
+}
+"#,
+    );
+    assert_eq!(
+        Value::rescript_module(),
+        r#"module Value = {
+  @schema
+  @unboxed
+  type t =
+    | @as(null) Nothing
+    | Number(float)
+    | Text(string)
+}
+"#,
+    );
+}
+
+#[derive(RescriptType)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+#[test]
+fn test_generic_recursive_type() {
+    assert_eq!(
+        Tree::<()>::rescript_module(),
+        r#"module Tree = {
+  @schema
+  type rec t<'t> = {
+    value: 't,
+    children: array<t<'t>>,
+  }
+}
+"#,
+    );
+}
+
+#[derive(RescriptType)]
+struct Labeled<Écrit> {
+    value: Écrit,
+}
+
+#[test]
+fn test_non_ascii_type_param() {
+    assert_eq!(
+        Labeled::<()>::rescript_module(),
+        "module Labeled = {\n  @schema\n  type t<'écrit> = {\n    value: 'écrit,\n  }\n}\n",
+    );
+}
+
+#[test]
+fn test_struct_schema() {
+    assert_eq!(
+        Person::rescript_schema(),
+        r#"S.object(s => {
+  firstName: s.field("firstName", S.string),
+  age: s.field("age", S.int),
+  balance: s.field("balance", S.float),
+  email: s.field("e-mail", S.null(S.string)),
+  tags: s.field("tags", S.option(S.array(S.string))),
+  scores: s.field("scores", S.dict(S.int)),
+  address: s.field("address", Address.schema),
+})"#,
+    );
+    assert_eq!(Id::rescript_schema(), "S.int");
+    assert_eq!(
+        Pair::rescript_schema(),
+        "S.tuple(s => (s.item(0, S.string), s.item(1, S.bool)))",
+    );
+}
+
+#[test]
+fn test_mixed_enum_schema() {
+    assert_eq!(
+        Shape::rescript_schema(),
+        r#"S.union([
+  S.literal(Empty),
+  S.literal(Dot),
+  S.object(s => {
+    s.tag("TAG", "Circle")
+    Circle({radius: s.field("radius", S.float)})
+  }),
+  S.object(s => {
+    s.tag("TAG", "Rect")
+    Rect(s.field("_0", S.int), s.field("_1", S.int))
+  }),
+  S.object(s => {
+    s.tag("TAG", "Named")
+    Named(s.field("_0", S.string))
+  }),
+])"#,
+    );
+}
+
+#[derive(RescriptType)]
+enum Range {
+    Span(u32, #[serde(skip)] u32, u32),
+    All,
+}
+
+#[test]
+fn test_skipped_positional_field() {
+    assert_eq!(
+        Range::rescript_schema(),
+        r#"S.union([
+  S.object(s => {
+    s.tag("TAG", "Span")
+    Span(s.field("_0", S.int), s.field("_1", S.int))
+  }),
+  S.literal(All),
+])"#,
+    );
+}
+
+#[derive(RescriptType)]
+#[serde(tag = "kind")]
+enum Signal {
+    Stop,
+    Go { speed: u32 },
+}
+
+#[test]
+fn test_tagged_enum_schema() {
+    assert_eq!(
+        Signal::rescript_schema(),
+        r#"S.union([
+  S.literal(Stop),
+  S.object(s => {
+    s.tag("kind", "Go")
+    Go({speed: s.field("speed", S.int)})
+  }),
+])"#,
+    );
+    assert_eq!(
+        Value::rescript_schema(),
+        r#"S.union([
+  S.literal(Nothing),
+  S.float->S.shape(v => Number(v)),
+  S.string->S.shape(v => Text(v)),
+])"#,
+    );
+}
+
+#[test]
+fn test_generic_recursive_schema() {
+    assert_eq!(
+        Tree::<()>::rescript_schema(),
+        r#"(tSchema) => S.recursive(self => S.object(s => {
+  value: s.field("value", tSchema),
+  children: s.field("children", S.array(self)),
+}))"#,
+    );
+}
+
+mod api {
+    use serde_derive_rescript::RescriptType;
+
+    #[derive(RescriptType)]
+    pub struct Request {
+        pub person: super::Person,
+        pub shapes: Vec<super::Shape>,
+        pub tree: super::Tree<String>,
+    }
+}
+
+#[test]
+fn test_collector() {
+    let mut collector = Collector::new();
+    collector.add::<api::Request>().add::<Person>();
+
+    let modules: Vec<_> = collector
+        .modules()
+        .iter()
+        .map(|module| module.name)
+        .collect();
+    assert_eq!(modules, ["Address", "Person", "Shape", "Tree", "Request"]);
+
+    let files = collector.files();
+    let names: Vec<_> = files.iter().map(|file| file.name.as_str()).collect();
+    assert_eq!(names, ["RescriptType.res", "RescriptType_Api.res"]);
+    assert_eq!(
+        files[1].source,
+        format!(
+            "// Generated by serde_derive_rescript. Do not edit.\n\nopen RescriptType\n\n{}",
+            api::Request::rescript_module(),
+        ),
+    );
+    assert!(files[0].source.starts_with(&format!(
+        "// Generated by serde_derive_rescript. Do not edit.\n\n{}\n{}",
+        Address::rescript_module(),
+        Person::rescript_module(),
+    )));
+}
+
+fn serialize_addr<S>(addr: &std::net::IpAddr, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    serializer.collect_str(addr)
+}
+
+#[derive(RescriptType)]
+struct Connection {
+    #[rescript(type = "float", schema = "S.float")]
+    timeout: std::time::Duration,
+    #[serde(serialize_with = "serialize_addr")]
+    addr: std::net::IpAddr,
+    peer: Address,
+}
+
+#[test]
+fn test_foreign_field_types() {
+    assert_eq!(
+        Connection::rescript_module(),
+        "module Connection = {\n  @schema\n  type t = {\n    timeout: float,\n    addr: IpAddr.t,\n    peer: Address.t,\n  }\n}\n",
+    );
+    assert_eq!(
+        Connection::rescript_schema(),
+        "S.object(s => {\n  timeout: s.field(\"timeout\", S.float),\n  addr: s.field(\"addr\", IpAddr.schema),\n  peer: s.field(\"peer\", Address.schema),\n})",
+    );
+
+    let mut collector = Collector::new();
+    collector.add::<Connection>();
+    let modules: Vec<_> = collector
+        .modules()
+        .iter()
+        .map(|module| module.name)
+        .collect();
+    assert_eq!(modules, ["Address", "Connection"]);
+}
+
+#[derive(RescriptType)]
+#[serde(polymorphic)]
+enum Color {
+    Red,
+    #[serde(rename = "red-ish")]
+    RedIsh,
+}
+
+#[test]
+fn test_polymorphic_variant() {
+    assert_eq!(
+        Color::rescript_module(),
+        "module Color = {\n  @schema\n  type t = [#Red | #\"red-ish\"]\n}\n",
+    );
+    assert_eq!(
+        Color::rescript_schema(),
+        "S.union([\n  S.literal(#Red),\n  S.literal(#\"red-ish\"),\n])",
+    );
+}
+
+#[derive(RescriptType)]
+enum Level {
+    #[serde(as_int = 0)]
+    Low,
+    #[serde(as_int = 1)]
+    High,
+}
+
+#[test]
+fn test_int_variants() {
+    assert_eq!(
+        Level::rescript_module(),
+        "module Level = {\n  @schema\n  type t =\n    | @as(0) Low\n    | @as(1) High\n}\n",
+    );
+}
+
+#[derive(RescriptType)]
+enum Outcome {
+    #[serde(tag_value = true)]
+    Ok { value: i32 },
+    #[serde(tag_value = 0)]
+    Err(String),
+    #[serde(tag_value = null)]
+    Empty {},
+}
+
+#[test]
+fn test_typed_tag_values() {
+    assert_eq!(
+        Outcome::rescript_module(),
+        "module Outcome = {\n  @schema\n  type t =\n    | @as(true) Ok({value: int})\n    | @as(0) Err(string)\n    | @as(null) Empty({})\n}\n",
+    );
+    assert_eq!(
+        Outcome::rescript_schema(),
+        "S.union([\n  S.object(s => {\n    s.tag(\"TAG\", true)\n    Ok({value: s.field(\"value\", S.int)})\n  }),\n  S.object(s => {\n    s.tag(\"TAG\", 0)\n    Err(s.field(\"_0\", S.string))\n  }),\n  S.object(s => {\n    s.tag(\"TAG\", Null.null)\n    Empty({})\n  }),\n])",
+    );
+}
+
+#[derive(RescriptType)]
+enum Reading {
+    #[serde(tag_value = 1.0)]
+    Exact(f64),
+    #[serde(tag_value = 0.5)]
+    Approximate(f64),
+}
+
+#[test]
+fn test_float_tag_values() {
+    assert_eq!(
+        Reading::rescript_module(),
+        "module Reading = {\n  @schema\n  type t =\n    | @as(1) Exact(float)\n    | @as(0.5) Approximate(float)\n}\n",
+    );
+    assert!(Reading::rescript_schema().contains("s.tag(\"TAG\", 1)\n"));
+}
+
+#[derive(RescriptType)]
+#[serde(omit_none)]
+struct Patch {
+    name: Option<Option<String>>,
+}
+
+#[test]
+fn test_nullable_field() {
+    assert_eq!(
+        Patch::rescript_schema(),
+        "S.object(s => {\n  name: s.field(\"name\", S.option(S.null(S.string))),\n})",
+    );
+}
+
+#[derive(RescriptType)]
+struct Reply {
+    #[serde(rescript_result)]
+    outcome: Result<u32, String>,
+    raw: Result<u32, String>,
+}
+
+#[test]
+fn test_result_field() {
+    assert_eq!(
+        Reply::rescript_module(),
+        "module Reply = {\n  @schema\n  type t = {\n    outcome: result<int, string>,\n    raw: result<int, string>,\n  }\n}\n",
+    );
+    assert_eq!(
+        Reply::rescript_schema(),
+        "S.object(s => {\n  outcome: s.field(\"outcome\", S.union([S.object(s => { s.tag(\"TAG\", \"Ok\"); Ok(s.field(\"_0\", S.int)) }), S.object(s => { s.tag(\"TAG\", \"Error\"); Error(s.field(\"_0\", S.string)) })])),\n  raw: s.field(\"raw\", S.union([S.object(s => Ok(s.field(\"Ok\", S.int))), S.object(s => Error(s.field(\"Err\", S.string)))])),\n})",
+    );
+}
+
+#[derive(RescriptType)]
+#[serde(record)]
+struct Position(f64, f64);
+
+#[test]
+fn test_record_tuple_struct() {
+    assert_eq!(
+        Position::rescript_module(),
+        "module Position = {\n  @schema\n  type t = {\n    _0: float,\n    _1: float,\n  }\n}\n",
+    );
+    assert_eq!(
+        Position::rescript_schema(),
+        "S.object(s => {\n  _0: s.field(\"_0\", S.float),\n  _1: s.field(\"_1\", S.float),\n})",
+    );
+}
+
+#[derive(RescriptType)]
+struct Profile {
+    born_at: serde_derive_rescript_rt::Date,
+    balance: serde_derive_rescript_rt::BigInt<u64>,
+    links: serde_derive_rescript_rt::Dict<String>,
+    #[serde(skip_serializing_if = "serde_derive_rescript_rt::Nullable::is_undefined")]
+    nickname: serde_derive_rescript_rt::Nullable<String>,
+}
+
+#[test]
+fn test_runtime_wrappers() {
+    assert_eq!(
+        Profile::rescript_module(),
+        "module Profile = {\n  @schema\n  type t = {\n    bornAt: Date.t,\n    balance: bigint,\n    links: dict<string>,\n    nickname: option<string>,\n  }\n}\n",
+    );
+    assert_eq!(
+        Profile::rescript_schema(),
+        "S.object(s => {\n  bornAt: s.field(\"bornAt\", S.float->S.transform(_ => {parser: Date.fromTime, serializer: Date.getTime})),\n  balance: s.field(\"balance\", S.bigint),\n  links: s.field(\"links\", S.dict(S.string)),\n  nickname: s.fieldOr(\"nickname\", S.nullable(S.string), None),\n})",
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, RescriptType)]
+struct Author {
+    name: String,
+}
+
+#[derive(SerializeDto, DeserializeDto, RescriptType)]
+struct Post {
+    #[serde(with = "serde_derive_rescript_rt::date")]
+    created_at: SystemTime,
+    #[serde(with = "serde_derive_rescript_rt::bigint")]
+    views: u64,
+    #[serde(with = "serde_derive_rescript_rt::dict")]
+    authors: HashMap<String, Author>,
+}
+
+#[test]
+fn test_runtime_with_modules() {
+    assert_eq!(
+        Post::rescript_module(),
+        "module Post = {\n  @schema\n  type t = {\n    createdAt: Date.t,\n    views: bigint,\n    authors: dict<Author.t>,\n  }\n}\n",
+    );
+
+    let mut collector = Collector::new();
+    collector.add::<Post>();
+    let modules: Vec<_> = collector
+        .modules()
+        .iter()
+        .map(|module| module.name)
+        .collect();
+    assert_eq!(modules, ["Author", "Post"]);
+}
+
+mod calendar {
+    use serde_derive_rescript::RescriptType;
+
+    // Named like a wrapper of the runtime crate, but another exported type
+    #[derive(RescriptType)]
+    pub struct Date {
+        pub day: u8,
+    }
+}
+
+#[derive(RescriptType)]
+struct Holiday {
+    date: calendar::Date,
+}
+
+#[test]
+fn test_runtime_types_by_path() {
+    assert_eq!(
+        Holiday::rescript_schema(),
+        "S.object(s => {\n  date: s.field(\"date\", Date.schema),\n})",
+    );
+
+    let mut collector = Collector::new();
+    collector.add::<Holiday>();
+    let modules: Vec<_> = collector
+        .modules()
+        .iter()
+        .map(|module| module.name)
+        .collect();
+    assert_eq!(modules, ["Date", "Holiday"]);
+}
+
+#[derive(RescriptType)]
+#[rescript(name = "User")]
+struct UserResponse {
+    user_name: String,
+}
+
+#[test]
+fn test_module_name() {
+    assert_eq!(UserResponse::RESCRIPT_MODULE_NAME, "User");
+    assert_eq!(
+        UserResponse::rescript_module(),
+        "module User = {\n  @schema\n  type t = {\n    userName: string,\n  }\n}\n",
+    );
+
+    let mut collector = Collector::new();
+    collector.add::<UserResponse>();
+    let modules: Vec<_> = collector
+        .modules()
+        .iter()
+        .map(|module| module.name)
+        .collect();
+    assert_eq!(modules, ["User"]);
+}
+
+#[derive(RescriptType)]
+struct Team {
+    lead: UserResponse,
+    members: Vec<UserResponse>,
+}
+
+#[test]
+fn test_renamed_dependency() {
+    assert_eq!(
+        Team::rescript_module(),
+        "module Team = {\n  @schema\n  type t = {\n    lead: User.t,\n    members: array<User.t>,\n  }\n}\n",
+    );
+    assert_eq!(
+        Team::rescript_schema(),
+        "S.object(s => {\n  lead: s.field(\"lead\", User.schema),\n  members: s.field(\"members\", S.array(User.schema)),\n})",
+    );
+
+    let mut collector = Collector::new();
+    collector.add::<Team>();
+    let modules: Vec<_> = collector
+        .modules()
+        .iter()
+        .map(|module| module.name)
+        .collect();
+    assert_eq!(modules, ["User", "Team"]);
+}
+
+#[derive(RescriptType)]
+#[serde(tag = "type")]
+struct Import {
+    module: String,
+    r#open: bool,
+    #[serde(rename = "as")]
+    alias: String,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(RescriptType)]
+enum Access {
+    read,
+    Write { r#type: String },
+}
+
+#[derive(RescriptType)]
+#[serde(polymorphic)]
+enum Keyword {
+    #[serde(rename = "type")]
+    Type,
+}
+
+#[test]
+fn test_reserved_words() {
+    assert_eq!(
+        Import::rescript_module(),
+        "module Import = {\n  @schema\n  type t = {\n    @as(\"type\") type_: string,\n    @as(\"module\") module_: string,\n    @as(\"open\") open_: bool,\n    @as(\"as\") alias: string,\n  }\n}\n",
+    );
+    assert_eq!(
+        Import::rescript_schema(),
+        "S.object(s => {\n  type_: s.field(\"type\", S.literal(\"Import\")),\n  module_: s.field(\"module\", S.string),\n  open_: s.field(\"open\", S.bool),\n  alias: s.field(\"as\", S.string),\n})",
+    );
+    assert_eq!(
+        Access::rescript_module(),
+        "module Access = {\n  @schema\n  type t =\n    | @as(\"read\") Read\n    | Write({@as(\"type\") type_: string})\n}\n",
+    );
+    assert_eq!(
+        Keyword::rescript_module(),
+        "module Keyword = {\n  @schema\n  type t = [#\"type\"]\n}\n",
+    );
+}
@@ -0,0 +1,192 @@
+use serde_derive_rescript::{DeserializeDto, SerializeDto};
+use serde_derive_rescript_rt::{BigInt, Date, Dict, Dto, Nullable};
+use serde_json::{self as json, json};
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct Post {
+    #[serde(with = "serde_derive_rescript_rt::date")]
+    created_at: SystemTime,
+    updated_at: Date,
+}
+
+#[test]
+fn test_date() {
+    let value = Post {
+        created_at: UNIX_EPOCH + Duration::from_millis(1_700_000_000_123),
+        updated_at: Date(UNIX_EPOCH - Duration::from_micros(1_500)),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized["createdAt"].as_f64(), Some(1_700_000_000_123.0));
+    assert_eq!(serialized["updatedAt"].as_f64(), Some(-1.5));
+    let deserialized: Post = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let err = json::from_value::<Date>(json!(1e300)).unwrap_err();
+    assert!(err.to_string().ends_with("expected a valid Date.t"));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct Account {
+    #[serde(with = "serde_derive_rescript_rt::bigint")]
+    balance: i128,
+    id: BigInt<u64>,
+}
+
+#[test]
+fn test_bigint() {
+    let value = Account {
+        balance: -170_141_183_460_469_231_731_687_303_715_884_105_728,
+        id: BigInt(u64::MAX),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(
+        serialized,
+        json!({
+            "balance": "-170141183460469231731687303715884105728",
+            "id": "18446744073709551615",
+        }),
+    );
+    let deserialized: Account = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let deserialized: Account = json::from_value(json!({ "balance": 1, "id": 2 })).unwrap();
+    assert_eq!(
+        deserialized,
+        Account {
+            balance: 1,
+            id: BigInt(2),
+        },
+    );
+
+    let err = json::from_value::<BigInt<u8>>(json!("256")).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "invalid value: string \"256\", expected a bigint",
+    );
+
+    let bytes = bincode::serialize(&value).unwrap();
+    let deserialized: Account = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct Translations {
+    #[serde(with = "serde_derive_rescript_rt::dict")]
+    messages: HashMap<String, u8>,
+    extra: Dict<u8>,
+}
+
+#[test]
+fn test_dict() {
+    let mut extra = Dict::new();
+    extra.insert("b".to_owned(), 1);
+    extra.insert("10".to_owned(), 2);
+    extra.insert("a".to_owned(), 3);
+    extra.insert("2".to_owned(), 4);
+    extra.insert("02".to_owned(), 5);
+    assert_eq!(extra.insert("b".to_owned(), 6), Some(1));
+    assert_eq!(
+        extra.keys().collect::<Vec<_>>(),
+        ["2", "10", "b", "a", "02"],
+    );
+    assert_eq!(extra.remove("b"), Some(6));
+    assert_eq!(extra.remove("10"), Some(2));
+    assert_eq!(extra.get("a"), Some(&3));
+    assert_eq!(extra.get("02"), Some(&5));
+    extra.insert("b".to_owned(), 6);
+    extra.insert("10".to_owned(), 2);
+    assert_eq!(
+        extra.keys().collect::<Vec<_>>(),
+        ["2", "10", "a", "02", "b"],
+    );
+
+    let value = Translations {
+        messages: [("z", 1), ("1", 2), ("a", 3)]
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v))
+            .collect(),
+        extra,
+    };
+    let serialized = json::to_string(&value).unwrap();
+    assert_eq!(
+        serialized,
+        r#"{"messages":{"1":2,"a":3,"z":1},"extra":{"2":4,"10":2,"a":3,"02":5,"b":6}}"#,
+    );
+    let deserialized: Translations = json::from_str(&serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let deserialized: Dict<u8> = json::from_str(r#"{"x":1,"0":2,"x":3}"#).unwrap();
+    assert_eq!(
+        deserialized.into_iter().collect::<Vec<_>>(),
+        [("0".to_owned(), 2), ("x".to_owned(), 3)],
+    );
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct Patch {
+    #[serde(default, skip_serializing_if = "Nullable::is_undefined")]
+    name: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_undefined")]
+    email: Nullable<String>,
+    #[serde(default, skip_serializing_if = "Nullable::is_undefined")]
+    phone: Nullable<String>,
+}
+
+#[test]
+fn test_nullable() {
+    let value = Patch {
+        name: Nullable::Value("x".to_owned()),
+        email: Nullable::Null,
+        phone: Nullable::Undefined,
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "name": "x", "email": null }));
+    let deserialized: Patch = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+    assert_eq!(deserialized.name.into_option(), Some("x".to_owned()));
+}
+
+#[derive(serde::Serialize, serde::Deserialize, SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[rescript(dto)]
+enum Kind {
+    Created,
+    Renamed(String),
+}
+
+#[derive(serde::Serialize, serde::Deserialize, SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[rescript(dto)]
+struct Event {
+    event_id: u8,
+    #[rescript(dto)]
+    kind: Kind,
+    history: Vec<Dto<Kind>>,
+}
+
+#[test]
+fn test_dto() {
+    let value = Event {
+        event_id: 1,
+        kind: Kind::Renamed("x".to_owned()),
+        history: vec![Dto(Kind::Created), Dto(Kind::Renamed("w".to_owned()))],
+    };
+
+    let upstream = json!({
+        "event_id": 1,
+        "kind": { "Renamed": "x" },
+        "history": ["Created", { "TAG": "Renamed", "_0": "w" }],
+    });
+    assert_eq!(json::to_value(&value).unwrap(), upstream);
+    let deserialized: Event = json::from_value(upstream).unwrap();
+    assert_eq!(deserialized, value);
+
+    let rescript = json!({
+        "eventId": 1,
+        "kind": { "TAG": "Renamed", "_0": "x" },
+        "history": ["Created", { "TAG": "Renamed", "_0": "w" }],
+    });
+    assert_eq!(json::to_value(Dto(&value)).unwrap(), rescript);
+    let Dto(deserialized) = json::from_value::<Dto<Event>>(rescript).unwrap();
+    assert_eq!(deserialized, value);
+}
@@ -160,3 +160,316 @@ fn test_struct_with_none_skipped_if_field_serialization() {
     let serialized = json::to_value(&value).unwrap();
     assert_eq!(serialized, json!({ "fieldA": 42 }));
 }
+
+// This crate's Cargo.toml sets no `rename_all`, so camelCase applies
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct StructWithCrateDefaults {
+    field_a: usize,
+    r#type: String,
+}
+
+#[test]
+fn test_struct_with_crate_defaults() {
+    let value = StructWithCrateDefaults {
+        field_a: 42,
+        r#type: "hello".to_string(),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "fieldA": 42, "type": "hello" }));
+    let deserialized: StructWithCrateDefaults = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(rename_all = "none")]
+struct StructWithRustFieldNames {
+    field_a: usize,
+    #[serde(rename = "fieldB")]
+    field_b: String,
+}
+
+#[test]
+fn test_struct_with_rust_field_names() {
+    let value = StructWithRustFieldNames {
+        field_a: 42,
+        field_b: "hello".to_string(),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "field_a": 42, "fieldB": "hello" }));
+    let deserialized: StructWithRustFieldNames = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+fn deserialize_doubled<'de, D>(deserializer: D) -> Result<Option<usize>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let value: Option<usize> = serde::Deserialize::deserialize(deserializer)?;
+    Ok(value.map(|value| value * 2))
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(omit_none)]
+struct StructWithOmittedNone {
+    field_a: Option<usize>,
+    #[serde(deserialize_with = "deserialize_doubled")]
+    field_b: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_some")]
+    field_c: Option<usize>,
+}
+
+#[test]
+fn test_struct_with_omitted_none() {
+    let value = StructWithOmittedNone {
+        field_a: None,
+        field_b: None,
+        field_c: None,
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "fieldC": null }));
+
+    let deserialized: StructWithOmittedNone = json::from_value(json!({})).unwrap();
+    assert_eq!(deserialized, value);
+
+    let value = StructWithOmittedNone {
+        field_a: Some(1),
+        field_b: Some(2),
+        field_c: None,
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(
+        serialized,
+        json!({ "fieldA": 1, "fieldB": 2, "fieldC": null })
+    );
+
+    let deserialized: StructWithOmittedNone =
+        json::from_value(json!({ "fieldA": 1, "fieldB": 1 })).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(omit_none)]
+struct StructWithOmittedNoneCompact {
+    field_a: Option<usize>,
+    field_b: usize,
+}
+
+#[test]
+fn test_struct_with_omitted_none_compact() {
+    // Binary formats read fields by position, so `None` is written there
+    let value = StructWithOmittedNoneCompact {
+        field_a: None,
+        field_b: 1,
+    };
+    let serialized = bincode::serialize(&value).unwrap();
+    assert_eq!(serialized, [0, 1, 0, 0, 0, 0, 0, 0, 0]);
+    let deserialized: StructWithOmittedNoneCompact = bincode::deserialize(&serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(omit_none)]
+struct StructWithNullableField {
+    field_a: Option<Option<usize>>,
+}
+
+#[test]
+fn test_struct_with_nullable_field() {
+    for (value, expected) in [
+        (None, json!({})),
+        (Some(None), json!({ "fieldA": null })),
+        (Some(Some(1)), json!({ "fieldA": 1 })),
+    ] {
+        let value = StructWithNullableField { field_a: value };
+        let serialized = json::to_value(&value).unwrap();
+        assert_eq!(serialized, expected);
+        let deserialized: StructWithNullableField = json::from_value(serialized).unwrap();
+        assert_eq!(deserialized, value);
+    }
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct StructWithNestedOption {
+    field_a: Option<Option<usize>>,
+}
+
+#[test]
+fn test_struct_with_nested_option() {
+    // Without `omit_none`, `None` and `Some(None)` are both `null`, as upstream
+    for (value, expected) in [
+        (None, json!({ "fieldA": null })),
+        (Some(None), json!({ "fieldA": null })),
+        (Some(Some(1)), json!({ "fieldA": 1 })),
+    ] {
+        let serialized = json::to_value(StructWithNestedOption { field_a: value }).unwrap();
+        assert_eq!(serialized, expected);
+    }
+    let deserialized: StructWithNestedOption = json::from_value(json!({ "fieldA": null })).unwrap();
+    assert_eq!(deserialized, StructWithNestedOption { field_a: None });
+}
+
+#[test]
+fn test_struct_with_nullable_field_compact() {
+    // Binary formats read fields by position, so `None` is written there
+    for (value, expected) in [
+        (None, &[0][..]),
+        (Some(None), &[1, 0]),
+        (Some(Some(1)), &[1, 1, 1, 0, 0, 0, 0, 0, 0, 0]),
+    ] {
+        let value = StructWithNullableField { field_a: value };
+        let serialized = bincode::serialize(&value).unwrap();
+        assert_eq!(serialized, expected);
+        let deserialized: StructWithNullableField = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, value);
+    }
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct StructWithResult<T> {
+    #[serde(rescript_result)]
+    field_a: Result<T, String>,
+    #[serde(rescript_result)]
+    field_b: Result<(), Option<usize>>,
+}
+
+#[test]
+fn test_struct_with_result() {
+    let value = StructWithResult {
+        field_a: Ok(vec![1, 2]),
+        field_b: Err(None),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(
+        serialized,
+        json!({
+            "fieldA": { "TAG": "Ok", "_0": [1, 2] },
+            "fieldB": { "TAG": "Error", "_0": null },
+        })
+    );
+    let deserialized: StructWithResult<Vec<usize>> = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    // The payload may come before the tag, and is optional for `option` payloads
+    let deserialized: StructWithResult<Vec<usize>> =
+        json::from_str(r#"{"fieldA": {"_0": "x", "TAG": "Error"}, "fieldB": {"TAG": "Error"}}"#)
+            .unwrap();
+    assert_eq!(
+        deserialized,
+        StructWithResult {
+            field_a: Err("x".to_owned()),
+            field_b: Err(None),
+        }
+    );
+
+    let error = json::from_str::<StructWithResult<usize>>(
+        r#"{"fieldA": {"TAG": "Fail", "_0": 1}, "fieldB": {"TAG": "Ok", "_0": null}}"#,
+    )
+    .unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("unknown variant `Fail`, expected `Ok` or `Error`"));
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct UserId(String);
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+struct Point(i32, i32);
+
+#[test]
+fn test_tuple_structs() {
+    // Newtypes are unboxed and tuple structs are arrays, like ReScript tuples
+    let value = UserId("id".to_owned());
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!("id"));
+    let deserialized: UserId = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let value = Point(1, 2);
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!([1, 2]));
+    let deserialized: Point = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(record)]
+struct UserIdRecord(String);
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(record, deny_unknown_fields)]
+struct PointRecord(i32, #[serde(default)] i32);
+
+#[test]
+fn test_record_tuple_structs() {
+    let value = UserIdRecord("id".to_owned());
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "_0": "id" }));
+    let deserialized: UserIdRecord = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let value = PointRecord(1, 2);
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "_0": 1, "_1": 2 }));
+    let deserialized: PointRecord = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let deserialized: PointRecord = json::from_value(json!({ "_0": 1 })).unwrap();
+    assert_eq!(deserialized, PointRecord(1, 0));
+    assert!(json::from_value::<PointRecord>(json!({ "_0": 1, "_2": 2 })).is_err());
+}
+
+mod reexported {
+    pub use serde as serde_reexport;
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(crate = "crate::reexported::serde_reexport")]
+struct WithCratePath {
+    name: String,
+    #[serde(flatten)]
+    extra: std::collections::BTreeMap<String, i32>,
+}
+
+#[test]
+fn test_crate_path() {
+    let value = WithCratePath {
+        name: "x".to_owned(),
+        extra: [("count".to_owned(), 1)].into_iter().collect(),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "name": "x", "count": 1 }));
+    let deserialized: WithCratePath = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[rescript(omit_none)]
+struct NamespaceOptions {
+    name: Option<String>,
+    #[rescript(result)]
+    outcome: Result<u8, String>,
+}
+
+#[derive(SerializeDto, DeserializeDto, PartialEq, Debug)]
+#[serde(deny_unknown_fields)]
+#[rescript(record)]
+struct NamespaceRecord(u8, u8);
+
+#[test]
+fn test_rescript_namespace() {
+    let value = NamespaceOptions {
+        name: None,
+        outcome: Ok(1),
+    };
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "outcome": { "TAG": "Ok", "_0": 1 } }));
+    let deserialized: NamespaceOptions = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+
+    let value = NamespaceRecord(1, 2);
+    let serialized = json::to_value(&value).unwrap();
+    assert_eq!(serialized, json!({ "_0": 1, "_1": 2 }));
+    let deserialized: NamespaceRecord = json::from_value(serialized).unwrap();
+    assert_eq!(deserialized, value);
+}